@@ -1,8 +1,8 @@
-use crate::amount_limit::AmountLimit;
+use iost_core::AmountLimit;
 use serde::{Serialize, Deserialize};
 
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ABI {
     /// interface name
     pub name: String,
@@ -0,0 +1,161 @@
+use crate::amount::Amount;
+use crate::contracts::{auth, gas, ram};
+use crate::error::Error;
+use crate::get_ram_info::RamInfo;
+use crate::number::Decimal;
+use iost_core::Tx;
+use crate::tx_builder::TxBuilder;
+use crate::tx_response::TxResponse;
+use crate::transport::Transport;
+use crate::IOST;
+use iost_keys::keypair::Keypair;
+
+/// Shortest valid IOST account name
+pub const MIN_ACCOUNT_NAME_LEN: usize = 5;
+/// Longest valid IOST account name
+pub const MAX_ACCOUNT_NAME_LEN: usize = 11;
+/// Share of the current RAM price added to a derived RAM cost limit, as the price rises with
+/// every purchase until the transaction is packed
+pub const DEFAULT_RAM_COST_MARGIN: f64 = 0.1;
+
+/// Digits after the decimal point of `iost`, the token RAM is paid in
+const IOST_DECIMALS: u8 = 8;
+
+/// Check the IOST account name rules: 5 to 11 characters of `a-z`, `0-9` and `_`
+pub fn validate_account_name(name: &str) -> Result<(), Error> {
+    let valid_len = (MIN_ACCOUNT_NAME_LEN..=MAX_ACCOUNT_NAME_LEN).contains(&name.len());
+    let valid_chars = name.chars().all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'));
+    if valid_len && valid_chars {
+        Ok(())
+    } else {
+        Err(Error::InvalidAccountName(name.to_owned()))
+    }
+}
+
+/// Creates accounts paid for by an existing publisher, with a `signUp` of the new account,
+/// a `ram.iost/buy` of its initial RAM and a `gas.iost/pledge` of its initial gas in one transaction.
+#[derive(Clone)]
+pub struct AccountCreator {
+    publisher: String,
+    keypair: Keypair,
+    builder: TxBuilder,
+    ram_cost_margin: f64,
+}
+
+impl AccountCreator {
+    /// A creator publishing as `publisher`, signing with its `keypair`. The account name is
+    /// needed as IOST account names are not derived from keys.
+    pub fn new(publisher: &str, keypair: Keypair) -> Self {
+        AccountCreator {
+            publisher: publisher.to_owned(),
+            keypair,
+            builder: TxBuilder::new(publisher),
+            ram_cost_margin: DEFAULT_RAM_COST_MARGIN
+        }
+    }
+
+    /// Base the transactions on `builder`, e.g. one with `TxBuilder::with_chain_defaults`.
+    /// Its actions are kept in front of the account creation and its publisher is replaced.
+    pub fn tx_builder(mut self, builder: TxBuilder) -> Self {
+        self.builder = builder.publisher(&self.publisher);
+        self
+    }
+
+    /// Share of the RAM price added to a derived RAM cost limit, e.g. `0.2` for 20%
+    pub fn ram_cost_margin(mut self, ram_cost_margin: f64) -> Self {
+        self.ram_cost_margin = ram_cost_margin;
+        self
+    }
+
+    /// Limit of the IOST spent on `ram_bytes` of RAM: the buy price of `ram_info` plus the margin
+    pub fn ram_cost_limit(&self, ram_info: &RamInfo, ram_bytes: u64) -> Result<Amount, Error> {
+        let cost = ram_info.buy_price * ram_bytes as f64 * (1.0 + self.ram_cost_margin);
+        let units = (cost * 10f64.powi(i32::from(IOST_DECIMALS))).ceil();
+        Amount::from_decimal(&Decimal::new(units as i128, u32::from(IOST_DECIMALS)), IOST_DECIMALS, "iost")
+            .ok_or_else(|| Error::InvalidNumber(format!("RAM cost {} iost", cost)))
+    }
+
+    /// Build and sign the transaction creating the account `name` with the base58 public keys
+    /// `owner` and `active`, `ram_bytes` of RAM costing at most `max_ram_cost` and `gas_pledge`
+    /// of IOST pledged for gas. `ram_cost_limit` derives `max_ram_cost` from `getRAMInfo`.
+    pub fn create(&self, name: &str, owner: &str, active: &str, ram_bytes: u64, gas_pledge: &Amount, max_ram_cost: &Amount) -> Result<Tx, Error> {
+        validate_account_name(name)?;
+        let publisher = &self.publisher;
+        let mut tx = self.builder.clone()
+            .call(auth::sign_up(name, owner, active))
+            .call(ram::buy(publisher, name, ram_bytes, max_ram_cost))
+            .call(gas::pledge(publisher, name, gas_pledge))
+            .build()?;
+        tx.sign_as_publisher(&self.keypair)?;
+        Ok(tx)
+    }
+
+    /// `create` the account with the `ram_cost_limit` of the current `getRAMInfo` and send the
+    /// transaction with `IOST::send_tx`
+    pub async fn create_and_send<T: Transport>(&self, iost: &IOST<T>, name: &str, owner: &str, active: &str, ram_bytes: u64, gas_pledge: &Amount) -> Result<TxResponse, Error> {
+        let max_ram_cost = self.ram_cost_limit(&iost.get_ram_info().await?, ram_bytes)?;
+        let tx = self.create(name, owner, active, ram_bytes, gas_pledge, &max_ram_cost)?;
+        iost.send_tx(tx).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockNode;
+    use iost_core::AmountLimit;
+    use iost_keys::algorithm::KeyAlgorithm;
+
+    fn creator() -> AccountCreator {
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        AccountCreator::new("admin", keypair)
+    }
+
+    #[test]
+    fn validate_account_name_should_be_ok() {
+        assert!(validate_account_name("lispczz3").is_ok());
+        assert!(validate_account_name("a_b_c").is_ok());
+        assert!(validate_account_name("abcd").is_err());
+        assert!(validate_account_name("abcdefghijkl").is_err());
+        assert!(validate_account_name("Admin").is_err());
+        assert!(validate_account_name("ad-min").is_err());
+    }
+
+    #[test]
+    fn create_should_build_signed_tx() {
+        let pledge = Amount::parse("10", 8, "iost").unwrap();
+        let max_ram_cost = Amount::parse("5", 8, "iost").unwrap();
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        let tx = AccountCreator::new("admin", keypair).create("newaccount", "OWNERKEY", "ACTIVEKEY", 1024, &pledge, &max_ram_cost).unwrap();
+        let actions: Vec<_> = tx.actions.iter().map(|a| (a.contract.as_str(), a.action_name.as_str())).collect();
+        assert_eq!(actions, vec![("auth.iost", "signUp"), ("ram.iost", "buy"), ("gas.iost", "pledge")]);
+        assert_eq!(tx.actions[1].data, r#"["admin","newaccount",1024]"#);
+        assert_eq!(tx.actions[2].data, r#"["admin","newaccount","10.00000000"]"#);
+        assert_eq!(tx.amount_limit, vec![AmountLimit { token: "iost".to_string(), value: "15.00000000".to_string() }]);
+        assert_eq!(tx.publisher_sigs.len(), 1);
+        assert!(tx.publisher_sigs[0].verify(&tx.publish_hash().unwrap()).is_ok());
+
+        assert!(matches!(creator().create("bad", "OWNERKEY", "ACTIVEKEY", 1024, &pledge, &max_ram_cost), Err(Error::InvalidAccountName(_))));
+    }
+
+    #[test]
+    fn ram_cost_limit_should_add_margin_to_buy_price() {
+        let ram_info: RamInfo = serde_json::from_str(include_str!("../fixtures/getRAMInfo.json")).unwrap();
+        // 0.03047636207318172 iost/byte * 1024 bytes * 1.1, rounded up
+        assert_eq!(creator().ram_cost_limit(&ram_info, 1024).unwrap().value(), "34.32857424");
+        assert_eq!(creator().ram_cost_margin(0.0).ram_cost_limit(&ram_info, 1000).unwrap().value(), "30.47636208");
+    }
+
+    #[tokio::test]
+    async fn create_and_send_should_be_ok() {
+        let node = MockNode::start();
+        let pledge = Amount::parse("10", 8, "iost").unwrap();
+        let response = creator().create_and_send(&node.client(), "newaccount", "OWNERKEY", "ACTIVEKEY", 1024, &pledge).await;
+        assert!(response.is_ok());
+        let requests = node.requests();
+        assert_eq!(requests[0].path, "getRAMInfo");
+        assert_eq!(requests.last().unwrap().path, "sendTx");
+        let tx: Tx = serde_json::from_str(&requests.last().unwrap().body).unwrap();
+        assert_eq!(tx.amount_limit[0].value, "44.32857424");
+    }
+}
@@ -0,0 +1,290 @@
+use crate::error::Error;
+use crate::get_token_info::TokenInfo;
+use crate::number::Decimal;
+use crate::transport::Transport;
+use crate::IOST;
+use iost_core::AmountLimit;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// An exact amount of a token: a non-negative `Decimal` with at most `decimals` digits after the
+/// decimal point, the precision of `symbol`, that fits a `u128` of smallest units.
+///
+/// Formats as `"<value> <symbol>"` with all `decimals` digits, e.g. `"1.50000000 iost"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Amount {
+    value: Decimal,
+    decimals: u8,
+    symbol: String
+}
+
+impl Amount {
+    /// Parse a decimal `value` such as `"1.5"` of a token with `decimals` digits
+    pub fn parse(value: &str, decimals: u8, symbol: &str) -> Result<Self, Error> {
+        let decimal: Decimal = value.parse()?;
+        Amount::from_decimal(&decimal, decimals, symbol)
+            .ok_or_else(|| Error::InvalidNumber(format!("{} {}", value, symbol)))
+    }
+
+    /// Parse a decimal `value` of the token described by `token_info`
+    pub fn for_token(value: &str, token_info: &TokenInfo) -> Result<Self, Error> {
+        let decimals = decimals(token_info)?;
+        Amount::parse(value, decimals, &token_info.symbol)
+    }
+
+    /// Convert `value`, such as an account balance. `None` when it is negative or has more than
+    /// `decimals` digits after the decimal point
+    pub fn from_decimal(value: &Decimal, decimals: u8, symbol: &str) -> Option<Self> {
+        Amount {
+            value: Decimal::default(),
+            decimals,
+            symbol: symbol.to_string()
+        }.with_value(*value)
+    }
+
+    /// An amount of `raw` smallest units, e.g. `150000000` for `1.5` of a token with 8 decimals
+    pub fn from_raw(raw: u128, decimals: u8, symbol: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidNumber(format!("{} {}", raw, symbol));
+        let mantissa = i128::try_from(raw).map_err(|_| invalid())?;
+        Amount::from_decimal(&Decimal::new(mantissa, u32::from(decimals)), decimals, symbol)
+            .ok_or_else(invalid)
+    }
+
+    /// The amount in smallest units of the token
+    pub fn raw(&self) -> u128 {
+        // every constructor checks that the value fits at `decimals`
+        self.value.rescale(u32::from(self.decimals)).unwrap_or_default() as u128
+    }
+
+    /// The amount as a number of `symbol`
+    pub fn decimal(&self) -> &Decimal {
+        &self.value
+    }
+
+    /// Number of digits after the decimal point, `TokenInfo::decimal`
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Token symbol
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// The amount without the symbol, e.g. `"1.50000000"`, as used in action data and `AmountLimit`
+    pub fn value(&self) -> String {
+        format!("{:.*}", self.decimals as usize, self.value)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.value.to_f64()
+    }
+
+    /// A zero amount of the same token
+    pub fn zero(&self) -> Self {
+        Amount {
+            value: Decimal::default(),
+            decimals: self.decimals,
+            symbol: self.symbol.clone()
+        }
+    }
+
+    /// `None` when `value` is negative or does not fit in smallest units of the token
+    fn with_value(&self, value: Decimal) -> Option<Self> {
+        if value.rescale(u32::from(self.decimals))? < 0 {
+            return None;
+        }
+        Some(Amount {
+            value,
+            decimals: self.decimals,
+            symbol: self.symbol.clone()
+        })
+    }
+
+    fn same_token(&self, other: &Amount) -> bool {
+        self.decimals == other.decimals && self.symbol == other.symbol
+    }
+
+    /// `None` on overflow or when `other` is another token
+    pub fn checked_add(&self, other: &Amount) -> Option<Self> {
+        if !self.same_token(other) {
+            return None;
+        }
+        self.with_value(self.value.checked_add(&other.value)?)
+    }
+
+    /// `None` when `other` is larger or another token
+    pub fn checked_sub(&self, other: &Amount) -> Option<Self> {
+        if !self.same_token(other) {
+            return None;
+        }
+        self.with_value(self.value.checked_sub(&other.value)?)
+    }
+
+    pub fn checked_mul(&self, factor: u128) -> Option<Self> {
+        let mantissa = self.value.mantissa().checked_mul(i128::try_from(factor).ok()?)?;
+        self.with_value(Decimal::new(mantissa, self.value.scale()))
+    }
+
+    /// Integer division, rounding down to the smallest unit
+    pub fn checked_div(&self, divisor: u128) -> Option<Self> {
+        let scale = u32::from(self.decimals);
+        let mantissa = self.value.rescale(scale)?.checked_div(i128::try_from(divisor).ok()?)?;
+        self.with_value(Decimal::new(mantissa, scale))
+    }
+}
+
+fn decimals(token_info: &TokenInfo) -> Result<u8, Error> {
+    u8::try_from(token_info.decimal)
+        .map_err(|_| Error::InvalidNumber(format!("decimal {} of {}", token_info.decimal, token_info.symbol)))
+}
+
+/// Amounts of different tokens are not comparable
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Amount) -> Option<Ordering> {
+        if self.same_token(other) {
+            Some(self.value.cmp(&other.value))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.value(), self.symbol)
+    }
+}
+
+/// Parse `"<value> <symbol>"`, taking the decimals from the digits of `value`
+impl FromStr for Amount {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(value), Some(symbol), None) => {
+                let decimals = value.find('.').map(|dot| value.len() - dot - 1).unwrap_or(0);
+                let decimals = u8::try_from(decimals).map_err(|_| Error::InvalidNumber(s.to_owned()))?;
+                Amount::parse(value, decimals, symbol)
+            }
+            _ => Err(Error::InvalidNumber(s.to_owned())),
+        }
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl From<&Amount> for AmountLimit {
+    fn from(amount: &Amount) -> Self {
+        AmountLimit {
+            token: amount.symbol.clone(),
+            value: amount.value()
+        }
+    }
+}
+
+impl<T: Transport> IOST<T> {
+    /// Parse a decimal `value` of the token `symbol`, taking its decimals from `getTokenInfo`
+    pub async fn token_amount(&self, value: &str, symbol: &str) -> Result<Amount, Error> {
+        let token_info = self.get_token_info(symbol, true).await?;
+        Amount::for_token(value, &token_info)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockNode;
+
+    #[test]
+    fn amount_should_parse_and_format() {
+        let amount = Amount::parse("1.5", 8, "iost").unwrap();
+        assert_eq!(*amount.decimal(), Decimal::new(15, 1));
+        assert_eq!(amount.value(), "1.50000000");
+        assert_eq!(amount.to_string(), "1.50000000 iost");
+        assert_eq!(*Amount::parse("0.00000001", 8, "iost").unwrap().decimal(), Decimal::new(1, 8));
+        assert_eq!(Amount::parse("42", 0, "nft").unwrap().value(), "42");
+        assert_eq!(Amount::parse("0.005", 3, "ram").unwrap().value(), "0.005");
+        assert!(Amount::parse("0.000000001", 8, "iost").is_err());
+        assert!(Amount::parse("-1", 8, "iost").is_err());
+        assert!(Amount::parse("abc", 8, "iost").is_err());
+    }
+
+    #[test]
+    fn amount_should_convert_smallest_units() {
+        let amount = Amount::from_raw(150_000_000, 8, "iost").unwrap();
+        assert_eq!(amount, Amount::parse("1.5", 8, "iost").unwrap());
+        assert_eq!(amount.raw(), 150_000_000);
+        assert_eq!(Amount::parse("42", 0, "nft").unwrap().raw(), 42);
+        assert_eq!(Amount::from_raw(0, 8, "iost").unwrap().value(), "0.00000000");
+        assert!(Amount::from_raw(u128::MAX, 8, "iost").is_err());
+        assert!(Amount::parse("1", 8, "iost").unwrap().checked_mul(u128::from(u64::MAX)).unwrap().checked_mul(u128::from(u64::MAX)).is_none());
+    }
+
+    #[test]
+    fn amount_should_round_trip_through_serde() {
+        let amount = Amount::parse("982678652.12345678", 8, "iost").unwrap();
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, r#""982678652.12345678 iost""#);
+        assert_eq!(serde_json::from_str::<Amount>(&json).unwrap(), amount);
+        assert!(serde_json::from_str::<Amount>(r#""1.5""#).is_err());
+    }
+
+    #[test]
+    fn amount_should_do_checked_arithmetic() {
+        let a = Amount::parse("1.5", 8, "iost").unwrap();
+        let b = Amount::parse("0.25", 8, "iost").unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().value(), "1.75000000");
+        assert_eq!(a.checked_sub(&b).unwrap().value(), "1.25000000");
+        assert!(b.checked_sub(&a).is_none());
+        assert_eq!(a.checked_mul(3).unwrap().value(), "4.50000000");
+        assert_eq!(a.checked_div(4).unwrap().value(), "0.37500000");
+        assert_eq!(Amount::parse("0.00000001", 8, "iost").unwrap().checked_div(3).unwrap().value(), "0.00000000");
+        assert!(a.checked_div(0).is_none());
+        assert!(a > b);
+
+        let other = Amount::parse("1.5", 6, "usdt").unwrap();
+        assert!(a.checked_add(&other).is_none());
+        assert!(a.partial_cmp(&other).is_none());
+    }
+
+    #[test]
+    fn amount_should_convert_balances() {
+        let balance: Decimal = "982678652.5".parse().unwrap();
+        assert_eq!(Amount::from_decimal(&balance, 8, "iost").unwrap().value(), "982678652.50000000");
+        assert!(Amount::from_decimal(&balance, 0, "iost").is_none());
+
+        let account: serde_json::Value = serde_json::from_str(r#"{"balance": 98267865.12345678}"#).unwrap();
+        let balance: Decimal = serde_json::from_value(account["balance"].clone()).unwrap();
+        assert_eq!(Amount::from_decimal(&balance, 8, "iost").unwrap().value(), "98267865.12345678");
+    }
+
+    #[test]
+    fn amount_limit_from_amount_should_be_ok() {
+        let amount_limit = AmountLimit::from(&Amount::parse("1.5", 8, "iost").unwrap());
+        assert_eq!(amount_limit.token, "iost");
+        assert_eq!(amount_limit.value, "1.50000000");
+    }
+
+    #[tokio::test]
+    async fn token_amount_should_use_token_decimals() {
+        let node = MockNode::start();
+        let amount = node.client().token_amount("1.5", "iost").await.unwrap();
+        assert_eq!(amount.to_string(), "1.50000000 iost");
+    }
+}
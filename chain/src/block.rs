@@ -7,7 +7,8 @@ pub struct Block {
     /// block hash
     pub hash: String,
     /// block version number
-    pub version: String,
+    #[serde(with = "crate::number::string")]
+    pub version: u64,
     /// the hash of the parent block of this block
     pub parent_hash: String,
     /// the merkle tree hash of all transactions
@@ -15,15 +16,18 @@ pub struct Block {
     /// the merkle tree hash of all receipts
     pub tx_receipt_merkle_hash: String,
     /// block number
-    pub number: String,
+    #[serde(with = "crate::number::string")]
+    pub number: u64,
     /// public key of the block producer
     pub witness: String,
     /// time of block production
-    pub time: String,
+    #[serde(with = "crate::number::string")]
+    pub time: i64,
     /// total GAS consumption within the block
     pub gas_usage: f64,
     /// transaction number in the block
-    pub tx_count: String,
+    #[serde(with = "crate::number::string")]
+    pub tx_count: u64,
     /// (This key is reserved.)
     pub info: Info,
     /// all the transactions.
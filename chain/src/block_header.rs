@@ -0,0 +1,271 @@
+use crate::block::Block;
+use iost_core::bytes::to_vec;
+use crate::error::Error;
+use crate::get_chain_info::ChainInfo;
+use crate::info::Info;
+use crate::merkle::{decode_hash, Hash};
+use iost_core::Signature;
+use iost_core::sha3_256;
+use iost_derive::{Write, NumberBytes};
+
+/// Prefix of the legacy account-style key IDs of witnesses, followed by the base58 of the public
+/// key and a 4 byte checksum
+const LEGACY_ID_PREFIX: &str = "IOST";
+
+/// The signed part of a block in the IOST binary layout. Its SHA3-256 hash is `Block::hash`.
+#[derive(Clone, Default, Debug, PartialEq, Write, NumberBytes)]
+#[iost_root_path = "crate"]
+pub struct BlockHeader {
+    /// block version number
+    pub version: i64,
+    /// the hash of the parent block
+    pub parent_hash: Vec<u8>,
+    /// the merkle tree hash of all transactions
+    pub tx_merkle_hash: Vec<u8>,
+    /// the merkle tree hash of all receipts
+    pub tx_receipt_merkle_hash: Vec<u8>,
+    /// `Block::info` as the producer encoded it, see `encode_info`
+    pub info: Vec<u8>,
+    /// block number
+    pub number: i64,
+    /// public key of the block producer
+    pub witness: String,
+    /// time of block production
+    pub time: i64
+}
+
+impl BlockHeader {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        to_vec(self).map_err(Error::Write)
+    }
+
+    /// SHA3-256 hash of `to_bytes`, signed by the witness
+    pub fn hash(&self) -> Result<Hash, Error> {
+        Ok(sha3_256(&self.to_bytes()?))
+    }
+}
+
+/// Decode a base58 hash that is empty for an empty tree
+fn decode_optional_hash(hash: &str) -> Result<Vec<u8>, Error> {
+    if hash.is_empty() {
+        Ok(Vec::new())
+    } else {
+        Ok(decode_hash(hash)?.to_vec())
+    }
+}
+
+/// `info` as go-iost writes it into `BlockHead.Info`: `json.Marshal` of its `Info` struct, with
+/// the fields in declaration order and no whitespace. A nil `BatchIndex` is marshalled as `null`
+/// and an empty one as `[]`, which the API both returns as an empty `batch_index`.
+fn encode_info(info: &Info, nil_batch_index: bool) -> Vec<u8> {
+    let batch_index = if nil_batch_index && info.batch_index.is_empty() {
+        "null".to_owned()
+    } else {
+        let indices: Vec<String> = info.batch_index.iter().map(|i| i.to_string()).collect();
+        format!("[{}]", indices.join(","))
+    };
+    format!(r#"{{"mode":{},"thread":{},"batch_index":{}}}"#, info.mode, info.thread, batch_index).into_bytes()
+}
+
+/// Whether `witness`, a key ID in either format, is the public key `public_key`
+fn is_key_of(witness: &str, public_key: &[u8]) -> bool {
+    let decoded = iost_keys::base58::from(witness.strip_prefix(LEGACY_ID_PREFIX).unwrap_or(witness));
+    match decoded {
+        Ok(bytes) if witness.starts_with(LEGACY_ID_PREFIX) && bytes.len() == public_key.len() + 4 => bytes[..public_key.len()] == *public_key,
+        Ok(bytes) => bytes == public_key,
+        Err(_) => false,
+    }
+}
+
+impl ChainInfo {
+    /// Whether `witness` is in the current or pending witness list
+    pub fn is_witness(&self, witness: &str) -> bool {
+        self.witness_list.iter().chain(&self.pending_witness_list).any(|w| w == witness)
+    }
+}
+
+impl Block {
+    /// The header of the block, which the node hashes into `hash`. An empty `batch_index` is
+    /// encoded as the nil slice go-iost producers write.
+    pub fn header(&self) -> Result<BlockHeader, Error> {
+        self.header_with(true)
+    }
+
+    fn header_with(&self, nil_batch_index: bool) -> Result<BlockHeader, Error> {
+        Ok(BlockHeader {
+            version: self.version as i64,
+            parent_hash: decode_optional_hash(&self.parent_hash)?,
+            tx_merkle_hash: decode_optional_hash(&self.tx_merkle_hash)?,
+            tx_receipt_merkle_hash: decode_optional_hash(&self.tx_receipt_merkle_hash)?,
+            info: encode_info(&self.info, nil_batch_index),
+            number: self.number as i64,
+            witness: self.witness.clone(),
+            time: self.time
+        })
+    }
+
+    /// `hash` decoded, once checked to be the hash of the block header. With an empty
+    /// `batch_index` both of its encodings are tried.
+    pub fn header_hash(&self) -> Result<Hash, Error> {
+        let hash = decode_hash(&self.hash)?;
+        if self.header_with(true)?.hash()? == hash
+            || (self.info.batch_index.is_empty() && self.header_with(false)?.hash()? == hash) {
+            return Ok(hash);
+        }
+        Err(Error::InvalidBlock(format!("hash {} of block {} does not match its header", self.hash, self.number)))
+    }
+
+    /// Check that `hash` is the hash of the block header
+    pub fn verify_hash(&self) -> Result<(), Error> {
+        self.header_hash().map(|_| ())
+    }
+
+    /// Check that `witness` is a block producer according to `chain_info`
+    pub fn verify_witness(&self, chain_info: &ChainInfo) -> Result<(), Error> {
+        if !chain_info.is_witness(&self.witness) {
+            return Err(Error::InvalidBlock(format!("witness {} of block {} is not a producer", self.witness, self.number)));
+        }
+        Ok(())
+    }
+
+    /// Check the producer `signature` of the block, which must be made by `witness` over the
+    /// header hash, and `hash` against the header. The HTTP API does not return the signature,
+    /// so it comes from another source such as a peer of the network.
+    pub fn verify_signature(&self, signature: &Signature) -> Result<(), Error> {
+        if !is_key_of(&self.witness, &signature.public_key_bytes()?) {
+            return Err(Error::InvalidBlock(format!("block {} is signed by another key than witness {}", self.number, self.witness)));
+        }
+        Ok(signature.verify(&self.header_hash()?)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::fixture_block;
+    use iost_keys::algorithm::KeyAlgorithm;
+    use iost_keys::keypair::Keypair;
+
+    /// Public key ID of `keypair(1)`
+    const WITNESS: &str = "AKnL4NNf3DGWZJS6cPknBuEGnVsV4A4m5tgebLHaRSZ9";
+
+    /// Header hashes of the fixture block produced by `WITNESS`, computed apart from this crate
+    /// from the go-iost header layout with each `info`
+    const NIL_BATCH_INDEX_HASH: &str = "FAHA6sEj1dC4Zr2MAaETCXtywkghNHgeodhpBWmUrj7m";
+    const EMPTY_BATCH_INDEX_HASH: &str = "o4YVS4aAcBW3Xiou8h9esme2rQUHwR3GzJvewWjPYd4";
+    const BATCH_INDEX_HASH: &str = "66n5LAyYT3kqzL3maV3FT2Kb82KkyojwWPkiviWXAzcR";
+
+    /// The fixture block produced by `WITNESS` with `info` and `hash`
+    fn vector_block(info: serde_json::Value, hash: &str) -> Block {
+        fixture_block(|block| {
+            block["witness"] = serde_json::json!(WITNESS);
+            block["info"] = info;
+            block["hash"] = serde_json::json!(hash);
+        })
+    }
+
+    fn serial_info() -> serde_json::Value {
+        serde_json::json!({"mode": 0, "thread": 0, "batch_index": []})
+    }
+
+    fn keypair(seed: u8) -> Keypair {
+        Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[seed; 32]).unwrap()
+    }
+
+    fn sign(block: &Block, keypair: &Keypair) -> Signature {
+        Signature::sign(&decode_hash(&block.hash).unwrap(), keypair).unwrap()
+    }
+
+    #[test]
+    fn block_header_should_encode_fields_in_order() {
+        let header = BlockHeader {
+            version: 1,
+            parent_hash: vec![0xaa],
+            tx_merkle_hash: vec![],
+            tx_receipt_merkle_hash: vec![0xbb, 0xcc],
+            info: b"{}".to_vec(),
+            number: 3,
+            witness: "w".to_string(),
+            time: 5
+        };
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&1i64.to_be_bytes());
+        expected.extend_from_slice(&[0, 0, 0, 1, 0xaa]);
+        expected.extend_from_slice(&[0, 0, 0, 0]);
+        expected.extend_from_slice(&[0, 0, 0, 2, 0xbb, 0xcc]);
+        expected.extend_from_slice(&[0, 0, 0, 2, b'{', b'}']);
+        expected.extend_from_slice(&3i64.to_be_bytes());
+        expected.extend_from_slice(&[0, 0, 0, 1, b'w']);
+        expected.extend_from_slice(&5i64.to_be_bytes());
+        assert_eq!(header.to_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn info_should_encode_as_go_json() {
+        let info: Info = serde_json::from_value(serde_json::json!({"mode": 1, "thread": 4, "batch_index": [0, 3, 7]})).unwrap();
+        assert_eq!(encode_info(&info, true), br#"{"mode":1,"thread":4,"batch_index":[0,3,7]}"#.to_vec());
+        let info: Info = serde_json::from_value(serial_info()).unwrap();
+        assert_eq!(encode_info(&info, true), br#"{"mode":0,"thread":0,"batch_index":null}"#.to_vec());
+        assert_eq!(encode_info(&info, false), br#"{"mode":0,"thread":0,"batch_index":[]}"#.to_vec());
+    }
+
+    #[test]
+    fn block_hash_should_match_header() {
+        assert_eq!(keypair(1).public_key_iost_string(), WITNESS);
+        let mut block = vector_block(serial_info(), NIL_BATCH_INDEX_HASH);
+        assert!(block.verify_hash().is_ok());
+        assert!(vector_block(serial_info(), EMPTY_BATCH_INDEX_HASH).verify_hash().is_ok());
+        let concurrent = serde_json::json!({"mode": 1, "thread": 4, "batch_index": [0, 3, 7]});
+        assert!(vector_block(concurrent.clone(), BATCH_INDEX_HASH).verify_hash().is_ok());
+        assert!(vector_block(concurrent, NIL_BATCH_INDEX_HASH).verify_hash().is_err());
+        block.time += 1;
+        assert!(matches!(block.verify_hash(), Err(Error::InvalidBlock(_))));
+    }
+
+    #[test]
+    fn block_signature_should_verify_against_witness() {
+        let block = vector_block(serial_info(), NIL_BATCH_INDEX_HASH);
+        let signature = sign(&block, &keypair(1));
+        assert!(block.verify_signature(&signature).is_ok());
+
+        let other = sign(&block, &keypair(2));
+        assert!(matches!(block.verify_signature(&other), Err(Error::InvalidBlock(_))));
+
+        let mut forged = signature.clone();
+        forged.signature = Signature::sign(&[0u8; 32], &keypair(1)).unwrap().signature;
+        assert!(block.verify_signature(&forged).is_err());
+    }
+
+    #[test]
+    fn witness_should_be_a_producer() {
+        let chain_info: ChainInfo = serde_json::from_str(include_str!("../fixtures/getChainInfo.json")).unwrap();
+        let mut block = fixture_block(|_| ());
+        assert!(block.verify_witness(&chain_info).is_ok());
+        block.witness = keypair(1).public_key_iost_string();
+        assert!(matches!(block.verify_witness(&chain_info), Err(Error::InvalidBlock(_))));
+    }
+
+    #[test]
+    fn legacy_witness_ids_should_match_their_key() {
+        let public_key = keypair(1).public_key_bytes();
+        let mut legacy = public_key.clone();
+        legacy.extend_from_slice(&[1, 2, 3, 4]);
+        let legacy = format!("IOST{}", iost_keys::base58::encode_slice(&legacy));
+        assert!(is_key_of(&legacy, &public_key));
+        assert!(is_key_of(&iost_keys::base58::encode_slice(&public_key), &public_key));
+        assert!(!is_key_of(&legacy, &keypair(2).public_key_bytes()));
+    }
+
+    /// Checks the hashes of the last irreversible mainnet blocks as the node serves them. Needs
+    /// access to api.iost.io: `cargo test mainnet -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn mainnet_block_hashes_should_verify() {
+        let iost = crate::IOST::new("https://api.iost.io");
+        let chain_info = iost.get_chain_info().await.unwrap();
+        for number in chain_info.lib_block.saturating_sub(20)..=chain_info.lib_block {
+            let block = iost.get_block_by_number(number, false).await.unwrap().block;
+            block.verify_hash().unwrap();
+        }
+    }
+}
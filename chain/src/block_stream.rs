@@ -0,0 +1,363 @@
+use crate::block::Block;
+use crate::confirm::POLL_INTERVAL;
+use crate::error::Error;
+use crate::transport::{HttpTransport, Transport};
+use crate::IOST;
+use futures::future::try_join_all;
+use futures::stream::{self, Stream};
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Number of blocks fetched concurrently by a new `BlockStream`
+pub const DEFAULT_WINDOW: usize = 8;
+
+/// Which end of the chain a `BlockStream` follows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Follow {
+    /// the head block, which may be reverted by a fork
+    Head,
+    /// the last irreversible block
+    Irreversible,
+}
+
+/// Number and hash of a block
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct BlockId {
+    /// block number
+    pub number: u64,
+    /// block hash
+    pub hash: String
+}
+
+impl BlockId {
+    pub fn of(block: &Block) -> Self {
+        BlockId {
+            number: block.number,
+            hash: block.hash.clone()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BlockEvent {
+    /// a block extending the followed chain
+    New(Block),
+    /// a block that an earlier `New` emitted and that a fork removed from the chain
+    Reverted(BlockId),
+    /// a block that an earlier `New` emitted and that can no longer be reverted
+    Irreversible(BlockId),
+}
+
+/// Where a `BlockStream` is on the chain. Keep it up to date with `apply` for every event that
+/// was processed and persist it to resume the stream from there.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Checkpoint {
+    /// the last block that became irreversible
+    pub irreversible: Option<BlockId>,
+    /// the blocks on top of it that may still be reverted, oldest first
+    pub reversible: Vec<BlockId>
+}
+
+impl Checkpoint {
+    /// The last block emitted and not reverted
+    pub fn tip(&self) -> Option<&BlockId> {
+        self.reversible.last().or(self.irreversible.as_ref())
+    }
+
+    pub fn apply(&mut self, event: &BlockEvent) {
+        match event {
+            BlockEvent::New(block) => self.reversible.push(BlockId::of(block)),
+            BlockEvent::Reverted(id) => {
+                if self.reversible.last() == Some(id) {
+                    self.reversible.pop();
+                }
+            }
+            BlockEvent::Irreversible(id) => {
+                if let Some(i) = self.reversible.iter().position(|b| b == id) {
+                    self.reversible.drain(..=i);
+                }
+                self.irreversible = Some(id.clone());
+            }
+        }
+    }
+}
+
+/// Follows the chain with `getChainInfo` and `getBlockByNumber`, fetching up to `window` blocks
+/// concurrently. A block whose `parent_hash` is not the hash of the previous block reveals a
+/// fork: the blocks above the fork point are `Reverted` and the new branch is emitted.
+///
+/// Following the LIB, every `New` block is directly followed by its `Irreversible` event.
+pub struct BlockStream<'a, T = HttpTransport> {
+    iost: &'a IOST<T>,
+    follow: Follow,
+    checkpoint: Checkpoint,
+    start: Option<u64>,
+    window: usize,
+    poll_interval: Duration,
+    complete: bool,
+}
+
+impl<'a, T: Transport> BlockStream<'a, T> {
+    /// A stream following the head from the current head block
+    pub fn new(iost: &'a IOST<T>) -> Self {
+        BlockStream {
+            iost,
+            follow: Follow::Head,
+            checkpoint: Checkpoint::default(),
+            start: None,
+            window: DEFAULT_WINDOW,
+            poll_interval: POLL_INTERVAL,
+            complete: false
+        }
+    }
+
+    pub fn follow(mut self, follow: Follow) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Continue after the tip of a persisted `checkpoint`
+    pub fn resume(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Start at block `number` when there is no checkpoint to resume from
+    pub fn start_at(mut self, number: u64) -> Self {
+        self.start = Some(number);
+        self
+    }
+
+    /// Maximum number of blocks fetched concurrently, at least 1
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Time to wait for new blocks once the stream caught up
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Fetch the blocks with their transactions
+    pub fn complete(mut self, complete: bool) -> Self {
+        self.complete = complete;
+        self
+    }
+
+    /// Endless stream of the block events. Errors are yielded `poll_interval` after they occur
+    /// and the stream retries on the next poll, except for a fork below the last irreversible
+    /// block, which cannot be followed and ends the stream after its error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<BlockEvent, Error>> + 'a {
+        let follower = Follower {
+            stream: self,
+            pending: VecDeque::new(),
+            ended: false
+        };
+        stream::unfold(Some(follower), |follower| async move {
+            let mut follower = follower?;
+            match follower.next_event().await {
+                Ok(event) => Some((Ok(event), Some(follower))),
+                Err(e) if follower.ended => Some((Err(e), None)),
+                Err(e) => {
+                    tokio::time::delay_for(follower.stream.poll_interval).await;
+                    Some((Err(e), Some(follower)))
+                }
+            }
+        })
+    }
+}
+
+struct Follower<'a, T> {
+    stream: BlockStream<'a, T>,
+    pending: VecDeque<BlockEvent>,
+    /// set when the chain forked below the last irreversible block
+    ended: bool,
+}
+
+impl<'a, T: Transport> Follower<'a, T> {
+    async fn next_event(&mut self) -> Result<BlockEvent, Error> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+            self.poll().await?;
+        }
+    }
+
+    fn emit(&mut self, event: BlockEvent) {
+        self.stream.checkpoint.apply(&event);
+        self.pending.push_back(event);
+    }
+
+    async fn poll(&mut self) -> Result<(), Error> {
+        let stream = &self.stream;
+        let chain_info = stream.iost.get_chain_info().await?;
+        let target = match stream.follow {
+            Follow::Head => chain_info.head_block,
+            Follow::Irreversible => chain_info.lib_block,
+        };
+        let next = match stream.checkpoint.tip() {
+            Some(tip) => tip.number + 1,
+            None => stream.start.unwrap_or(target),
+        };
+        if next > target {
+            tokio::time::delay_for(stream.poll_interval).await;
+            return Ok(());
+        }
+        let end = target.min(next + stream.window as u64 - 1);
+        let blocks = try_join_all((next..=end).map(|n| stream.iost.get_block_by_number(n, stream.complete))).await?;
+
+        for block in blocks.into_iter().map(|b| b.block) {
+            if let Some(tip) = self.stream.checkpoint.tip() {
+                if block.parent_hash != tip.hash {
+                    match self.stream.checkpoint.reversible.last().cloned() {
+                        Some(reverted) => {
+                            self.emit(BlockEvent::Reverted(reverted));
+                            break;
+                        }
+                        None => {
+                            self.ended = true;
+                            return Err(Error::UnexpectedResponse(format!(
+                                "block {} does not extend irreversible block {}", block.number, tip.number)));
+                        }
+                    }
+                }
+            }
+            let id = BlockId::of(&block);
+            self.emit(BlockEvent::New(block));
+            if self.stream.follow == Follow::Irreversible {
+                self.emit(BlockEvent::Irreversible(id));
+            }
+        }
+
+        if self.stream.follow == Follow::Head {
+            let irreversible: Vec<BlockId> = self.stream.checkpoint.reversible.iter()
+                .take_while(|id| id.number <= chain_info.lib_block)
+                .cloned()
+                .collect();
+            for id in irreversible {
+                self.emit(BlockEvent::Irreversible(id));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::{fixture_block, MockNode};
+    use futures::StreamExt;
+    use serde_json::json;
+
+    fn respond_chain(node: &MockNode, head: u64, lib: u64) {
+        let mut chain_info: serde_json::Value = serde_json::from_str(include_str!("../fixtures/getChainInfo.json")).unwrap();
+        chain_info["head_block"] = json!(head.to_string());
+        chain_info["lib_block"] = json!(lib.to_string());
+        node.respond("getChainInfo", &chain_info.to_string());
+    }
+
+    fn respond_block(node: &MockNode, number: u64, hash: &str, parent_hash: &str) {
+        let block = fixture_block(|block| {
+            block["number"] = json!(number.to_string());
+            block["hash"] = json!(hash);
+            block["parent_hash"] = json!(parent_hash);
+        });
+        let response = json!({"status": "IRREVERSIBLE", "block": block});
+        node.respond(&format!("getBlockByNumber/{}", number), &response.to_string());
+    }
+
+    fn describe(event: &BlockEvent) -> String {
+        match event {
+            BlockEvent::New(block) => format!("new {}", block.hash),
+            BlockEvent::Reverted(id) => format!("reverted {}", id.hash),
+            BlockEvent::Irreversible(id) => format!("irreversible {}", id.hash),
+        }
+    }
+
+    async fn next_events<S: Stream<Item = Result<BlockEvent, Error>> + Unpin>(stream: &mut S, n: usize, checkpoint: &mut Checkpoint) -> Vec<String> {
+        let mut events = Vec::new();
+        for _ in 0..n {
+            let event = stream.next().await.unwrap().unwrap();
+            checkpoint.apply(&event);
+            events.push(describe(&event));
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn block_stream_should_follow_head_and_revert_forks() {
+        let node = MockNode::empty();
+        respond_chain(&node, 3, 1);
+        respond_block(&node, 1, "a1", "a0");
+        respond_block(&node, 2, "a2", "a1");
+        respond_block(&node, 3, "a3", "a2");
+        let iost = node.client();
+        let mut checkpoint = Checkpoint::default();
+        let mut stream = Box::pin(BlockStream::new(&iost).start_at(1).window(2).poll_interval(Duration::from_millis(10)).into_stream());
+
+        let events = next_events(&mut stream, 4, &mut checkpoint).await;
+        assert_eq!(events, vec!["new a1", "new a2", "irreversible a1", "new a3"]);
+
+        respond_chain(&node, 4, 2);
+        respond_block(&node, 3, "b3", "a2");
+        respond_block(&node, 4, "b4", "b3");
+        let events = next_events(&mut stream, 4, &mut checkpoint).await;
+        assert_eq!(events, vec!["reverted a3", "irreversible a2", "new b3", "new b4"]);
+        assert_eq!(checkpoint.irreversible.as_ref().unwrap().hash, "a2");
+        assert_eq!(checkpoint.reversible.iter().map(|b| b.hash.as_str()).collect::<Vec<_>>(), vec!["b3", "b4"]);
+    }
+
+    #[tokio::test]
+    async fn block_stream_should_resume_from_checkpoint() {
+        let node = MockNode::empty();
+        respond_chain(&node, 6, 5);
+        respond_block(&node, 5, "a5", "a4");
+        respond_block(&node, 6, "a6", "a5");
+        let iost = node.client();
+        let mut checkpoint = Checkpoint {
+            irreversible: Some(BlockId { number: 4, hash: "a4".to_string() }),
+            reversible: vec![]
+        };
+        let mut stream = Box::pin(BlockStream::new(&iost).follow(Follow::Irreversible).resume(checkpoint.clone()).into_stream());
+        let events = next_events(&mut stream, 2, &mut checkpoint).await;
+        assert_eq!(events, vec!["new a5", "irreversible a5"]);
+        assert_eq!(checkpoint.tip().unwrap().number, 5);
+        assert!(checkpoint.reversible.is_empty());
+        assert!(node.requests().iter().all(|r| !r.path.starts_with("getBlockByNumber/6")));
+    }
+
+    #[tokio::test]
+    async fn block_stream_should_not_revert_irreversible_blocks() {
+        let node = MockNode::empty();
+        respond_chain(&node, 5, 5);
+        respond_block(&node, 5, "b5", "b4");
+        let iost = node.client();
+        let checkpoint = Checkpoint {
+            irreversible: Some(BlockId { number: 4, hash: "a4".to_string() }),
+            reversible: vec![]
+        };
+        let mut stream = Box::pin(BlockStream::new(&iost).resume(checkpoint).into_stream());
+        assert!(matches!(stream.next().await.unwrap(), Err(Error::UnexpectedResponse(_))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn block_stream_should_wait_before_yielding_errors() {
+        let node = MockNode::empty();
+        node.respond_error("getChainInfo", 400, 2, "node is syncing");
+        let iost = node.client();
+        let poll_interval = Duration::from_millis(50);
+        let mut stream = Box::pin(BlockStream::new(&iost).start_at(1).poll_interval(poll_interval).into_stream());
+        let started = std::time::Instant::now();
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(started.elapsed() >= poll_interval);
+
+        respond_chain(&node, 1, 1);
+        respond_block(&node, 1, "a1", "a0");
+        assert_eq!(describe(&stream.next().await.unwrap().unwrap()), "new a1");
+    }
+}
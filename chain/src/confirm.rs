@@ -0,0 +1,137 @@
+use crate::error::Error;
+use crate::message::ErrorMessage;
+use crate::status::Status;
+use iost_core::Tx;
+use crate::tx_receipt::TxReceipt;
+use crate::transport::Transport;
+use crate::tx_builder::now;
+use crate::IOST;
+use std::time::{Duration, Instant};
+
+/// Time between two polls of the transaction status, the block interval of IOST
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How far a transaction must have progressed before it counts as confirmed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Confirmation {
+    /// the transaction is in a block that may still be reverted
+    Packed,
+    /// the transaction is in an irreversible block
+    Irreversible,
+}
+
+impl Confirmation {
+    /// Whether a transaction with `status` is confirmed
+    pub fn is_reached(&self, status: &Status) -> bool {
+        matches!((self, status), (Confirmation::Packed, Status::PACKED) | (_, Status::IRREVERSIBLE))
+    }
+}
+
+impl<T: Transport> IOST<T> {
+    /// Send a signed transaction and wait until it is confirmed, returning its receipt.
+    /// Fails with `TxExpired` once the transaction passed its expiration without being packed,
+    /// and with `ConfirmationTimeout` when it is not confirmed within `timeout`.
+    pub async fn send_and_confirm(&self, tx: Tx, confirmation: Confirmation, timeout: Duration) -> Result<TxReceipt, Error> {
+        let expiration = tx.expiration;
+        let response = self.send_tx(tx).await?;
+        self.confirm_tx(&response.hash, expiration, confirmation, timeout).await
+    }
+
+    /// Wait until the transaction `hash` expiring at `expiration`, in nanoseconds since the Unix
+    /// epoch, is confirmed, polling `getTxByHash` and then fetching its receipt.
+    pub async fn confirm_tx(&self, hash: &str, expiration: i64, confirmation: Confirmation, timeout: Duration) -> Result<TxReceipt, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let packed = match self.get_tx_by_hash(hash).await {
+                Ok(tx) if confirmation.is_reached(&tx.status) => {
+                    return self.get_tx_receipt_by_tx_hash(hash).await;
+                }
+                Ok(tx) => !matches!(tx.status, Status::PENDING),
+                Err(Error::NotFound(_)) => false,
+                Err(e) => return Err(e),
+            };
+            if !packed && now() > expiration {
+                return Err(Error::TxExpired(ErrorMessage {
+                    code: 0,
+                    message: format!("transaction {} expired before being packed", hash)
+                }));
+            }
+            if Instant::now() + POLL_INTERVAL > deadline {
+                return Err(Error::ConfirmationTimeout(hash.to_owned()));
+            }
+            tokio::time::delay_for(POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockNode;
+    use crate::retry::RetryPolicy;
+
+    const HASH: &str = "Dj8bmA4Fx4LHrwLtDB6EEkNbBFU8biENxf55mNaJewYw";
+
+    fn tx_by_hash(status: &str) -> String {
+        include_str!("../fixtures/getTxByHash.json").replacen("IRREVERSIBLE", status, 1)
+    }
+
+    fn future_expiration() -> i64 {
+        now() + 90_000_000_000
+    }
+
+    #[test]
+    fn confirmation_should_follow_status() {
+        assert!(!Confirmation::Packed.is_reached(&Status::PENDING));
+        assert!(Confirmation::Packed.is_reached(&Status::PACKED));
+        assert!(Confirmation::Packed.is_reached(&Status::IRREVERSIBLE));
+        assert!(!Confirmation::Irreversible.is_reached(&Status::PACKED));
+        assert!(Confirmation::Irreversible.is_reached(&Status::IRREVERSIBLE));
+    }
+
+    #[tokio::test]
+    async fn send_and_confirm_should_be_ok() {
+        let node = MockNode::start();
+        let tx = Tx { expiration: future_expiration(), ..Default::default() };
+        let receipt = node.client().send_and_confirm(tx, Confirmation::Irreversible, Duration::from_secs(5)).await.unwrap();
+        assert_eq!(receipt.tx_hash, HASH);
+        let paths: Vec<String> = node.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(paths, vec!["sendTx".to_string(), format!("getTxByHash/{}", HASH), format!("getTxReceiptByTxHash/{}", HASH)]);
+    }
+
+    #[tokio::test]
+    async fn confirm_tx_should_wait_for_irreversible() {
+        let node = MockNode::start();
+        node.respond("getTxByHash", &tx_by_hash("PENDING"));
+        let iost = node.client();
+        let progress = async {
+            tokio::time::delay_for(POLL_INTERVAL / 2).await;
+            node.respond("getTxByHash", &tx_by_hash("PACKED"));
+            tokio::time::delay_for(POLL_INTERVAL).await;
+            node.respond("getTxByHash", &tx_by_hash("IRREVERSIBLE"));
+        };
+        let confirm = iost.confirm_tx(HASH, future_expiration(), Confirmation::Irreversible, Duration::from_secs(5));
+        let (receipt, _) = futures::join!(confirm, progress);
+        assert_eq!(receipt.unwrap().tx_hash, HASH);
+        assert!(node.requests().iter().filter(|r| r.path.starts_with("getTxByHash")).count() >= 3);
+    }
+
+    #[tokio::test]
+    async fn confirm_tx_should_accept_packed() {
+        let node = MockNode::start();
+        node.respond("getTxByHash", &tx_by_hash("PACKED"));
+        let iost = node.client();
+        assert!(iost.confirm_tx(HASH, future_expiration(), Confirmation::Packed, Duration::from_secs(5)).await.is_ok());
+        let result = iost.confirm_tx(HASH, future_expiration(), Confirmation::Irreversible, Duration::from_millis(600)).await;
+        assert!(matches!(result, Err(Error::ConfirmationTimeout(_))));
+    }
+
+    #[tokio::test]
+    async fn confirm_tx_should_report_expiration() {
+        let node = MockNode::start();
+        node.respond_error("getTxByHash", 404, 5, "tx not found");
+        let iost = node.client().retry_policy(RetryPolicy::none());
+        let result = iost.confirm_tx(HASH, now() - 1, Confirmation::Packed, Duration::from_secs(5)).await;
+        assert!(matches!(result, Err(Error::TxExpired(_))));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::abi::ABI;
+use iost_core::Action;
+use crate::contracts::ContractCall;
+use crate::error::Error;
+use crate::get_contract::Contract;
+use crate::transport::Transport;
+use crate::IOST;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Builds calls of a deployed contract, checked against the argument types of its ABI
+/// (`string`, `number`, `bool` and `json`) before a transaction is ever sent.
+#[derive(Debug)]
+pub struct ContractClient {
+    contract: Contract
+}
+
+impl ContractClient {
+    pub fn new(contract: Contract) -> Self {
+        ContractClient { contract }
+    }
+
+    /// Fetch the ABI of `contract_id` with `getContract`
+    pub async fn load<T: Transport>(iost: &IOST<T>, contract_id: &str) -> Result<Self, Error> {
+        let contract = iost.get_contract(contract_id, true).await?;
+        Ok(ContractClient::new(contract))
+    }
+
+    /// contract ID
+    pub fn id(&self) -> &str {
+        &self.contract.id
+    }
+
+    /// The ABI of `action_name`, if the contract declares it
+    pub fn abi(&self, action_name: &str) -> Option<&ABI> {
+        self.contract.abis.iter().find(|abi| abi.name == action_name)
+    }
+
+    /// Call `action_name` with `args`, which must serialize to an array matching the declared
+    /// argument types. The call carries the amount limits declared in the ABI.
+    pub fn call<T: Serialize>(&self, action_name: &str, args: &T) -> Result<ContractCall, Error> {
+        let abi = self.abi(action_name)
+            .ok_or_else(|| Error::InvalidCall(format!("{} has no action {}", self.contract.id, action_name)))?;
+        let args = match serde_json::to_value(args).map_err(Error::Json)? {
+            Value::Array(args) => args,
+            value => return Err(Error::InvalidActionData(value.to_string())),
+        };
+        if args.len() != abi.args.len() {
+            return Err(Error::InvalidCall(format!("{}/{} takes {} arguments but {} were given",
+                self.contract.id, action_name, abi.args.len(), args.len())));
+        }
+        for (i, (arg, arg_type)) in args.iter().zip(&abi.args).enumerate() {
+            check_arg(arg, arg_type).map_err(|expected| Error::InvalidCall(format!("argument {} of {}/{} must be {}, got {}",
+                i, self.contract.id, action_name, expected, arg)))?;
+        }
+        Ok(ContractCall {
+            action: Action::with_args(&self.contract.id, action_name, &args)?,
+            amount_limits: abi.amount_limit.clone()
+        })
+    }
+}
+
+/// Check `arg` against an ABI type, returning what was expected on a mismatch. A `json`
+/// argument is an object or array, or a string holding one.
+fn check_arg(arg: &Value, arg_type: &str) -> Result<(), String> {
+    let is_json = |value: &Value| value.is_object() || value.is_array();
+    let valid = match arg_type {
+        "string" => arg.is_string(),
+        "number" => arg.is_number(),
+        "bool" => arg.is_boolean(),
+        "json" => match arg {
+            Value::String(s) => matches!(serde_json::from_str::<Value>(s), Ok(ref value) if is_json(value)),
+            _ => is_json(arg),
+        },
+        _ => return Err(format!("of the unsupported type {}", arg_type)),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("a {}", arg_type))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use iost_core::AmountLimit;
+    use crate::mock::MockNode;
+    use serde_json::json;
+
+    fn token_contract() -> ContractClient {
+        let contract = serde_json::from_value(json!({
+            "id": "token.iost",
+            "code": "",
+            "language": "native",
+            "version": "1.0.0",
+            "abis": [
+                {"name": "transfer", "args": ["string", "string", "string", "string", "string"], "amount_limit": [{"token": "*", "value": "unlimited"}]},
+                {"name": "issue", "args": ["string", "string", "number"], "amount_limit": []},
+                {"name": "create", "args": ["string", "string", "number", "json"], "amount_limit": []},
+                {"name": "enable", "args": ["bool", "blob"], "amount_limit": []}
+            ]
+        })).unwrap();
+        ContractClient::new(contract)
+    }
+
+    #[test]
+    fn call_should_encode_checked_args() {
+        let client = token_contract();
+        let call = client.call("transfer", &("iost", "admin", "lispczz3", "1.5", "")).unwrap();
+        assert_eq!(call.action.contract, "token.iost");
+        assert_eq!(call.action.data, r#"["iost","admin","lispczz3","1.5",""]"#);
+        assert_eq!(call.amount_limits, vec![AmountLimit { token: "*".to_string(), value: "unlimited".to_string() }]);
+
+        let call = client.call("create", &json!(["abc", "admin", 100, {"decimal": 2}])).unwrap();
+        assert!(call.amount_limits.is_empty());
+        assert!(client.call("create", &json!(["abc", "admin", 100, r#"{"decimal": 2}"#])).is_ok());
+    }
+
+    #[test]
+    fn call_should_reject_mismatches() {
+        let client = token_contract();
+        let message = |result: Result<ContractCall, Error>| match result {
+            Err(Error::InvalidCall(message)) => message,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(message(client.call("burn", &("iost",))), "token.iost has no action burn");
+        assert_eq!(message(client.call("issue", &("iost", "admin"))), "token.iost/issue takes 3 arguments but 2 were given");
+        assert_eq!(message(client.call("issue", &("iost", "admin", "100"))), r#"argument 2 of token.iost/issue must be a number, got "100""#);
+        assert_eq!(message(client.call("create", &json!(["abc", "admin", 100, "{not json"]))), r#"argument 3 of token.iost/create must be a json, got "{not json""#);
+        assert_eq!(message(client.call("create", &json!(["abc", "admin", 100, 2]))), "argument 3 of token.iost/create must be a json, got 2");
+        assert!(client.call("create", &json!(["abc", "admin", 100, true])).is_err());
+        assert!(client.call("create", &json!(["abc", "admin", 100, "2"])).is_err());
+        assert!(client.call("create", &json!(["abc", "admin", 100, [2]])).is_ok());
+        assert!(message(client.call("enable", &(true, "x"))).contains("unsupported type blob"));
+        assert!(matches!(client.call("issue", &"iost"), Err(Error::InvalidActionData(_))));
+    }
+
+    #[tokio::test]
+    async fn load_should_fetch_abi() {
+        let node = MockNode::start();
+        let client = ContractClient::load(&node.client(), "base.iost").await.unwrap();
+        assert_eq!(client.id(), "base.iost");
+        assert!(client.abi("exec").is_some());
+        assert!(client.call("can_update", &("data",)).is_ok());
+        assert!(client.call("can_update", &(1,)).is_err());
+    }
+}
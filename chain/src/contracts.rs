@@ -0,0 +1,204 @@
+//! Typed action constructors for the built-in system contracts
+
+use iost_core::Action;
+use crate::amount::Amount;
+use iost_core::AmountLimit;
+use serde::Serialize;
+
+/// An action together with the amount limits it needs to be executed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractCall {
+    /// the contract call
+    pub action: Action,
+    /// tokens the call spends from the signers
+    pub amount_limits: Vec<AmountLimit>
+}
+
+fn call<T: Serialize>(contract: &str, action_name: &str, args: &T, amount_limits: Vec<AmountLimit>) -> ContractCall {
+    ContractCall {
+        action: Action::with_args(contract, action_name, args).expect("contract arguments are a tuple"),
+        amount_limits
+    }
+}
+
+/// `token.iost`, transfers of IOST and other tokens
+pub mod token {
+    use super::*;
+
+    pub const CONTRACT: &str = "token.iost";
+
+    /// Transfer `amount` of its token from `from` to `to`
+    pub fn transfer(amount: &Amount, from: &str, to: &str, memo: &str) -> ContractCall {
+        call(CONTRACT, "transfer", &(amount.symbol(), from, to, amount.value(), memo), vec![AmountLimit::from(amount)])
+    }
+
+    /// Transfer `amount` from `from` to `to`, frozen until `unfreeze_time` in nanoseconds since the Unix epoch
+    pub fn transfer_freeze(amount: &Amount, from: &str, to: &str, unfreeze_time: i64, memo: &str) -> ContractCall {
+        call(CONTRACT, "transferFreeze", &(amount.symbol(), from, to, amount.value(), unfreeze_time, memo), vec![AmountLimit::from(amount)])
+    }
+}
+
+/// `gas.iost`, pledging IOST for gas
+pub mod gas {
+    use super::*;
+
+    pub const CONTRACT: &str = "gas.iost";
+
+    /// Pledge `amount` of IOST from `pledger` to get gas for `gas_user`
+    pub fn pledge(pledger: &str, gas_user: &str, amount: &Amount) -> ContractCall {
+        call(CONTRACT, "pledge", &(pledger, gas_user, amount.value()), vec![AmountLimit::from(amount)])
+    }
+
+    /// Take back `amount` of IOST that `pledger` pledged for `gas_user`
+    pub fn unpledge(pledger: &str, gas_user: &str, amount: &Amount) -> ContractCall {
+        call(CONTRACT, "unpledge", &(pledger, gas_user, amount.value()), vec![])
+    }
+}
+
+/// `ram.iost`, trading RAM in bytes
+pub mod ram {
+    use super::*;
+
+    pub const CONTRACT: &str = "ram.iost";
+
+    /// Buy `bytes` of RAM for `receiver`, paid by `payer` with at most `max_cost` of IOST
+    pub fn buy(payer: &str, receiver: &str, bytes: u64, max_cost: &Amount) -> ContractCall {
+        call(CONTRACT, "buy", &(payer, receiver, bytes), vec![AmountLimit::from(max_cost)])
+    }
+
+    /// Sell `bytes` of RAM of `seller`, paying the IOST to `receiver`
+    pub fn sell(seller: &str, receiver: &str, bytes: u64) -> ContractCall {
+        call(CONTRACT, "sell", &(seller, receiver, bytes), vec![])
+    }
+
+    /// Lend `bytes` of RAM from `from` to `to`
+    pub fn lend(from: &str, to: &str, bytes: u64) -> ContractCall {
+        call(CONTRACT, "lend", &(from, to, bytes), vec![AmountLimit {
+            token: "ram".to_string(),
+            value: bytes.to_string()
+        }])
+    }
+}
+
+/// `vote_producer.iost`, voting for block producers
+pub mod vote_producer {
+    use super::*;
+
+    pub const CONTRACT: &str = "vote_producer.iost";
+
+    /// Vote for `producer` with `amount` of IOST of `voter`
+    pub fn vote(voter: &str, producer: &str, amount: &Amount) -> ContractCall {
+        call(CONTRACT, "vote", &(voter, producer, amount.value()), vec![AmountLimit::from(amount)])
+    }
+
+    /// Withdraw `amount` of the votes of `voter` for `producer`
+    pub fn unvote(voter: &str, producer: &str, amount: &Amount) -> ContractCall {
+        call(CONTRACT, "unvote", &(voter, producer, amount.value()), vec![])
+    }
+}
+
+/// `auth.iost`, accounts and permissions
+pub mod auth {
+    use super::*;
+
+    pub const CONTRACT: &str = "auth.iost";
+
+    /// Create the account `name` with the base58 public keys of its `owner` and `active` permissions
+    pub fn sign_up(name: &str, owner: &str, active: &str) -> ContractCall {
+        call(CONTRACT, "signUp", &(name, owner, active), vec![])
+    }
+
+    /// Add the permission `permission` with `threshold` to the account `id`
+    pub fn add_permission(id: &str, permission: &str, threshold: i64) -> ContractCall {
+        call(CONTRACT, "addPermission", &(id, permission, threshold), vec![])
+    }
+
+    /// Remove the permission `permission` from the account `id`
+    pub fn drop_permission(id: &str, permission: &str) -> ContractCall {
+        call(CONTRACT, "dropPermission", &(id, permission), vec![])
+    }
+
+    /// Grant `permission` of the account `id` to `user`, a public key or `account@permission`, with `weight`
+    pub fn assign_permission(id: &str, permission: &str, user: &str, weight: i64) -> ContractCall {
+        call(CONTRACT, "assignPermission", &(id, permission, user, weight), vec![])
+    }
+
+    /// Revoke `permission` of the account `id` from `user`
+    pub fn revoke_permission(id: &str, permission: &str, user: &str) -> ContractCall {
+        call(CONTRACT, "revokePermission", &(id, permission, user), vec![])
+    }
+}
+
+/// `bonus.iost`, block producer rewards
+pub mod bonus {
+    use super::*;
+
+    pub const CONTRACT: &str = "bonus.iost";
+
+    /// Exchange `amount` of the `contribute` token of `account` for IOST
+    pub fn exchange_iost(account: &str, amount: &Amount) -> ContractCall {
+        call(CONTRACT, "exchangeIOST", &(account, amount.value()), vec![AmountLimit {
+            token: "contribute".to_string(),
+            value: amount.value()
+        }])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn iost(value: &str) -> Amount {
+        Amount::parse(value, 8, "iost").unwrap()
+    }
+
+    #[test]
+    fn token_transfer_should_be_ok() {
+        let call = token::transfer(&iost("100"), "testaccount", "anothertest", "memo");
+        assert_eq!(call.action.contract, "token.iost");
+        assert_eq!(call.action.action_name, "transfer");
+        assert_eq!(call.action.data, r#"["iost","testaccount","anothertest","100.00000000","memo"]"#);
+        assert_eq!(call.amount_limits, vec![AmountLimit { token: "iost".to_string(), value: "100.00000000".to_string() }]);
+
+        let call = token::transfer_freeze(&iost("1"), "a", "b", 1565000000000000000, "");
+        assert_eq!(call.action.data, r#"["iost","a","b","1.00000000",1565000000000000000,""]"#);
+    }
+
+    #[test]
+    fn gas_and_vote_should_be_ok() {
+        let call = gas::pledge("admin", "user", &iost("10"));
+        assert_eq!(call.action.data, r#"["admin","user","10.00000000"]"#);
+        assert_eq!(call.amount_limits[0].token, "iost");
+        assert!(gas::unpledge("admin", "user", &iost("10")).amount_limits.is_empty());
+
+        let call = vote_producer::vote("admin", "producer", &iost("2100000"));
+        assert_eq!(call.action.contract, "vote_producer.iost");
+        assert_eq!(call.action.data, r#"["admin","producer","2100000.00000000"]"#);
+        assert_eq!(call.amount_limits[0].value, "2100000.00000000");
+    }
+
+    #[test]
+    fn ram_should_be_ok() {
+        let call = ram::buy("admin", "user", 1024, &iost("50"));
+        assert_eq!(call.action.data, r#"["admin","user",1024]"#);
+        assert_eq!(call.amount_limits[0].value, "50.00000000");
+        assert!(ram::sell("admin", "admin", 1024).amount_limits.is_empty());
+        assert_eq!(ram::lend("admin", "user", 10).amount_limits[0], AmountLimit { token: "ram".to_string(), value: "10".to_string() });
+    }
+
+    #[test]
+    fn auth_and_bonus_should_be_ok() {
+        let call = auth::sign_up("newaccount", "OWNERKEY", "ACTIVEKEY");
+        assert_eq!(call.action.action_name, "signUp");
+        assert_eq!(call.action.data, r#"["newaccount","OWNERKEY","ACTIVEKEY"]"#);
+
+        let call = auth::add_permission("admin", "perm1", 100);
+        assert_eq!(call.action.data, r#"["admin","perm1",100]"#);
+        let call = auth::assign_permission("admin", "perm1", "other@active", 50);
+        assert_eq!(call.action.data, r#"["admin","perm1","other@active",50]"#);
+
+        let call = bonus::exchange_iost("admin", &iost("1.5"));
+        assert_eq!(call.action.data, r#"["admin","1.50000000"]"#);
+        assert_eq!(call.amount_limits[0].token, "contribute");
+    }
+}
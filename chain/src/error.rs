@@ -1,9 +1,248 @@
+use iost_core::bytes::{ReadError, WriteError};
 use crate::message::ErrorMessage;
+use crate::tx_receipt::TxReceipt;
+use core::fmt;
+
+/// gRPC status code the node answers with when it gives no specific reason
+pub const CODE_UNKNOWN: i32 = 2;
+/// gRPC status code the node answers with when a block, transaction or account does not exist
+pub const CODE_NOT_FOUND: i32 = 5;
+/// gRPC status code the node answers with when the transaction is already known
+pub const CODE_ALREADY_EXISTS: i32 = 6;
 
 #[derive(Debug)]
 pub enum Error {
     ///Error request message
     Reqwest(reqwest::Error),
-    ///Error response message
-    ErrorMessage(ErrorMessage)
+    ///Error request that timed out
+    Timeout(reqwest::Error),
+    ///Error of a transport other than `HttpTransport`
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    ///Error response message that could not be classified
+    ErrorMessage(ErrorMessage),
+    ///Error block, transaction, account or contract that does not exist
+    NotFound(ErrorMessage),
+    ///Error transaction whose expiration has passed
+    TxExpired(ErrorMessage),
+    ///Error transaction without enough gas to pay for it
+    InsufficientGas(ErrorMessage),
+    ///Error transaction the node already has
+    DuplicateTx(ErrorMessage),
+    ///Error binary deserialization
+    Read(ReadError),
+    ///Error binary serialization
+    Write(WriteError),
+    ///Error key operation
+    Keys(iost_keys::error::Error),
+    ///Error JSON decoding
+    Json(serde_json::Error),
+    ///Error base64 decoding
+    Base64(base64::DecodeError),
+    ///Error action data that is not a JSON array
+    InvalidActionData(String),
+    ///Error unsupported signature algorithm
+    UnsupportedAlgorithm(String),
+    ///Error dry run of a transaction that did not succeed
+    DryRunFailed(Box<TxReceipt>),
+    ///Error transaction that violates IOST constraints
+    InvalidTx(String),
+    ///Error transaction not confirmed within the timeout
+    ConfirmationTimeout(String),
+    ///Error string that is not a valid number
+    InvalidNumber(String),
+    ///Error name that violates the IOST account name rules
+    InvalidAccountName(String),
+    ///Error signatures that do not meet the threshold of a permission
+    PermissionDenied(String),
+    ///Error contract call that does not match the ABI of the contract
+    InvalidCall(String),
+    ///Error read from another block than the one a reader is pinned to
+    BlockChanged(String),
+    ///Error response of the node that does not match the request
+    UnexpectedResponse(String),
+    ///Error block that does not match its contents
+    InvalidBlock(String)
+}
+
+impl Error {
+    /// The error response of the node, if the error comes from one
+    pub fn error_message(&self) -> Option<&ErrorMessage> {
+        match self {
+            Error::ErrorMessage(message)
+            | Error::NotFound(message)
+            | Error::TxExpired(message)
+            | Error::InsufficientGas(message)
+            | Error::DuplicateTx(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Reqwest(e) => write!(f, "request failed: {}", e),
+            Error::Timeout(e) => write!(f, "request timed out: {}", e),
+            Error::Transport(e) => write!(f, "transport failed: {}", e),
+            Error::ErrorMessage(m) => write!(f, "node error {}: {}", m.code, m.message),
+            Error::NotFound(m) => write!(f, "not found: {}", m.message),
+            Error::TxExpired(m) => write!(f, "transaction expired: {}", m.message),
+            Error::InsufficientGas(m) => write!(f, "insufficient gas: {}", m.message),
+            Error::DuplicateTx(m) => write!(f, "duplicate transaction: {}", m.message),
+            Error::Read(e) => write!(f, "binary deserialization failed: {:?}", e),
+            Error::Write(e) => write!(f, "binary serialization failed: {:?}", e),
+            Error::Keys(e) => write!(f, "key operation failed: {}", e),
+            Error::Json(e) => write!(f, "JSON decoding failed: {}", e),
+            Error::Base64(e) => write!(f, "base64 decoding failed: {}", e),
+            Error::InvalidActionData(data) => write!(f, "action data is not a JSON array: {}", data),
+            Error::UnsupportedAlgorithm(algorithm) => write!(f, "unsupported signature algorithm: {}", algorithm),
+            Error::DryRunFailed(receipt) => write!(f, "dry run failed with {:?}: {}", receipt.status_code, receipt.message),
+            Error::InvalidTx(reason) => write!(f, "invalid transaction: {}", reason),
+            Error::ConfirmationTimeout(hash) => write!(f, "transaction {} not confirmed in time", hash),
+            Error::InvalidNumber(s) => write!(f, "invalid number: {}", s),
+            Error::InvalidAccountName(name) => write!(f, "invalid account name: {}", name),
+            Error::PermissionDenied(reason) => write!(f, "permission denied: {}", reason),
+            Error::InvalidCall(reason) => write!(f, "invalid contract call: {}", reason),
+            Error::BlockChanged(reason) => write!(f, "block changed: {}", reason),
+            Error::UnexpectedResponse(reason) => write!(f, "unexpected response: {}", reason),
+            Error::InvalidBlock(reason) => write!(f, "invalid block: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Reqwest(e) | Error::Timeout(e) => Some(e),
+            Error::Transport(e) => Some(e.as_ref()),
+            Error::Json(e) => Some(e),
+            Error::Base64(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Error {
+        if e.is_timeout() {
+            Error::Timeout(e)
+        } else {
+            Error::Reqwest(e)
+        }
+    }
+}
+
+impl From<ErrorMessage> for Error {
+    /// Classify the error response of the node by its gRPC status code.
+    ///
+    /// Most errors of the node carry `CODE_UNKNOWN` with the reason only in the message,
+    /// so codes without a specific meaning fall back to matching the message text.
+    fn from(message: ErrorMessage) -> Error {
+        match message.code {
+            CODE_NOT_FOUND => Error::NotFound(message),
+            CODE_ALREADY_EXISTS => Error::DuplicateTx(message),
+            _ => classify_text(message),
+        }
+    }
+}
+
+/// Fallback classification by the message text of the node
+fn classify_text(message: ErrorMessage) -> Error {
+    let text = message.message.to_lowercase();
+    if text.contains("not found") || text.contains("not exist") {
+        Error::NotFound(message)
+    } else if text.contains("expired") {
+        Error::TxExpired(message)
+    } else if text.contains("gas") && (text.contains("not enough") || text.contains("insufficient") || text.contains("run out")) {
+        Error::InsufficientGas(message)
+    } else if text.contains("duplicate") || text.contains("exists in") {
+        Error::DuplicateTx(message)
+    } else {
+        Error::ErrorMessage(message)
+    }
+}
+
+impl From<iost_core::Error> for Error {
+    fn from(e: iost_core::Error) -> Error {
+        match e {
+            iost_core::Error::Read(e) => Error::Read(e),
+            iost_core::Error::Write(e) => Error::Write(e),
+            iost_core::Error::Keys(e) => Error::Keys(e),
+            iost_core::Error::Json(e) => Error::Json(e),
+            iost_core::Error::Base64(e) => Error::Base64(e),
+            iost_core::Error::InvalidActionData(data) => Error::InvalidActionData(data),
+            iost_core::Error::UnsupportedAlgorithm(algorithm) => Error::UnsupportedAlgorithm(algorithm),
+        }
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(e: ReadError) -> Error {
+        Error::Read(e)
+    }
+}
+
+impl From<WriteError> for Error {
+    fn from(e: WriteError) -> Error {
+        Error::Write(e)
+    }
+}
+
+impl From<iost_keys::error::Error> for Error {
+    fn from(e: iost_keys::error::Error) -> Error {
+        Error::Keys(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Error {
+        Error::Base64(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn message(code: i32, message: &str) -> ErrorMessage {
+        ErrorMessage { code, message: message.to_string() }
+    }
+
+    fn fixture(json: &str) -> Error {
+        Error::from(serde_json::from_str::<ErrorMessage>(json).unwrap())
+    }
+
+    #[test]
+    fn error_message_should_be_classified_by_code() {
+        assert!(matches!(fixture(include_str!("../fixtures/getTxByHash_error.json")), Error::NotFound(_)));
+        assert!(matches!(fixture(include_str!("../fixtures/sendTx_exists_error.json")), Error::DuplicateTx(_)));
+        assert!(matches!(Error::from(message(CODE_NOT_FOUND, "expired tx")), Error::NotFound(_)));
+    }
+
+    #[test]
+    fn error_message_should_fall_back_to_text() {
+        assert!(matches!(fixture(include_str!("../fixtures/getProducerVoteInfo_error.json")), Error::NotFound(_)));
+        assert!(matches!(fixture(include_str!("../fixtures/sendTx_expired_error.json")), Error::TxExpired(_)));
+        assert!(matches!(fixture(include_str!("../fixtures/execTx_gas_error.json")), Error::InsufficientGas(_)));
+        assert!(matches!(fixture(include_str!("../fixtures/sendTx_duplicate_error.json")), Error::DuplicateTx(_)));
+        assert!(matches!(Error::from(message(CODE_UNKNOWN, "account admin2 does not exist")), Error::NotFound(_)));
+        assert!(matches!(Error::from(message(CODE_UNKNOWN, "wrong signature")), Error::ErrorMessage(_)));
+    }
+
+    #[test]
+    fn error_should_display() {
+        let error = Error::from(message(2, "expired tx"));
+        assert_eq!(error.to_string(), "transaction expired: expired tx");
+        assert_eq!(error.error_message().unwrap().code, 2);
+        assert!(Error::InvalidTx("publisher is empty".to_string()).error_message().is_none());
+        let json = serde_json::from_str::<ErrorMessage>("{").unwrap_err();
+        let error: &dyn std::error::Error = &Error::from(json);
+        assert!(error.source().is_some());
+    }
 }
@@ -1,9 +1,11 @@
+use crate::number::Decimal;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FrozenBalance {
     /// the amount
-    pub amount: f64,
+    pub amount: Decimal,
     /// the time when the amount is unfrozen
-    pub time: String
+    #[serde(with = "crate::number::string")]
+    pub time: i64
 }
\ No newline at end of file
@@ -1,8 +1,7 @@
-use crate::error::Error;
+use crate::number::Decimal;
 use crate::frozen_balance::FrozenBalance;
 use crate::gas_info::GasInfo;
 use crate::group::Group;
-use crate::message::ErrorMessage;
 use crate::permission::Permission;
 use crate::ram_info::RAMInfo;
 use crate::vote_info::VoteInfo;
@@ -14,7 +13,7 @@ pub struct Account {
     /// account name
     pub name: String,
     /// the balance of the account
-    pub balance: f64,
+    pub balance: Decimal,
     /// Gas information
     pub gas_info: GasInfo,
     /// Ram information
@@ -29,25 +28,14 @@ pub struct Account {
     pub vote_infos: Vec<VoteInfo>
 }
 
-async fn get_account(domain: &str, account: &str, complete: bool) -> Result<Account, Error> {
-    let url = format!("{}/getAccount/{}/{}", domain, account, complete);
-    let req = reqwest::get(&url).await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<Account>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_account_should_be_ok() {
-        let response = get_account("http://api.iost.io","admin",true).await;
+        let node = MockNode::start();
+        let response = node.client().get_account("admin", true).await;
         assert!(response.is_ok());
     }
 }
@@ -1,4 +1,3 @@
-use crate::error::Error;
 use crate::key_field::KeyField;
 use serde::{Serialize, Deserialize};
 
@@ -19,28 +18,14 @@ pub struct BatchContractStorage {
     /// the hash of block from which the data is from
     pub block_hash: String,
     /// the number of block from which the data is from
-    pub block_number: String
-}
-
-async fn get_batch_contract_storage(new_post: BatchContractStoragePost) -> Result<BatchContractStorage, Error> {
-    let req = reqwest::Client::new()
-        .post("http://api.iost.io/getBatchContractStorage")
-        .json(&new_post)
-        .send()
-        .await.map_err(Error::Reqwest)?;
-    let status_code = req.status();
-    if status_code == 200 {
-        let response = req.json().await.map_err(Error::Reqwest)?;
-        Ok(response)
-    } else {
-        let response = req.json().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(response))
-    }
+    #[serde(with = "crate::number::string")]
+    pub block_number: u64
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::mock::MockNode;
     
     #[tokio::test]
     async fn get_batch_contract_storage_should_be_ok() {
@@ -60,7 +45,9 @@ mod test {
             by_longest_chain: true
         };
 
-        let res = get_batch_contract_storage(new_post).await;
+        let node = MockNode::start();
+
+        let res = node.client().get_batch_contract_storage(new_post).await;
         assert!(res.is_ok());
     }
 }
@@ -1,6 +1,4 @@
 use crate::block::Block;
-use crate::error::Error;
-use crate::message::ErrorMessage;
 use crate::status::Status;
 use serde::{Serialize, Deserialize};
 
@@ -20,43 +18,24 @@ pub struct BlockByNumber {
     pub block: Block
 }
 
-async fn get_block_by_hash(domain: &str, hash: &str, complete: bool) -> Result<BlockByHash, Error> {
-    let url = format!("{}/getBlockByHash/{}/{}", domain, hash, complete);
-    let req = reqwest::get(&url).await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<BlockByHash>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
-    }
-}
-
-async fn get_block_by_number(domain: &str, number: i32, complete: bool) -> Result<BlockByNumber, Error> {
-    let url = format!("{}/getBlockByNumber/{}/{}", domain, number, complete);
-    let req = reqwest::get(&url).await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<BlockByNumber>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_block_by_hash_should_be_ok() {
-        let response = get_block_by_hash("http://api.iost.io","GexerugLra5qBArG4vqCAFNX1F7WzLzpPdmzcjLBAi3k",false).await;
+        let node = MockNode::start();
+        let response = node.client().get_block_by_hash("GexerugLra5qBArG4vqCAFNX1F7WzLzpPdmzcjLBAi3k", false).await;
         assert!(response.is_ok());
+        let block = response.unwrap().block;
+        assert_eq!(block.number, 3);
+        assert_eq!(block.time, 1561963050500000000);
     }
 
     #[tokio::test]
     async fn get_block_by_number_should_be_ok() {
-        let response = get_block_by_number("http://api.iost.io",3,false).await;
+        let node = MockNode::start();
+        let response = node.client().get_block_by_number(3, false).await;
         assert!(response.is_ok());
     }
 }
@@ -7,13 +7,15 @@ pub struct ChainInfo {
     /// iost protocol version
     pub protocol_version: String,
     /// iost chain id
-    pub chain_id: i32,
+    pub chain_id: u32,
     /// the lastest block height
-    pub head_block: String,
+    #[serde(with = "crate::number::string")]
+    pub head_block: u64,
     /// the hash of the lastest block
     pub head_block_hash: String,
     /// height of irreversible blocks
-    pub lib_block: String,
+    #[serde(with = "crate::number::string")]
+    pub lib_block: u64,
     /// hash of irreversible blocks
     pub lib_block_hash: String,
     /// list of pubkeys for the current block production nodes
@@ -23,26 +25,24 @@ pub struct ChainInfo {
     /// list of pubkeys for the next round block production nodes
     pub pending_witness_list: Vec<String>,
     /// time of head block
-    pub head_block_time: String,
+    #[serde(with = "crate::number::string")]
+    pub head_block_time: i64,
     /// time of last irreversible block
-    pub lib_block_time: String
-}
-
-async fn get_chain_info() -> ChainInfo {
-    let res = reqwest::get("https://api.iost.io/getChainInfo").await.unwrap()
-        .json::<ChainInfo>()
-        .await.unwrap();
-    res
+    #[serde(with = "crate::number::string")]
+    pub lib_block_time: i64
 }
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_chain_info_should_be_ok() {
-
-        println!("{:#?}", get_chain_info().await);
-
+        let node = MockNode::start();
+        let response = node.client().get_chain_info().await;
+        assert!(response.is_ok());
+        let chain_info = response.unwrap();
+        assert_eq!(chain_info.head_block, 54283450);
+        assert_eq!(chain_info.lib_block_time, 1565253224001553000);
     }
 }
@@ -1,6 +1,4 @@
 use crate::abi::ABI;
-use crate::error::Error;
-use crate::message::ErrorMessage;
 use serde::{Deserialize};
 
 #[derive(Deserialize, Debug)]
@@ -17,25 +15,14 @@ pub struct Contract {
     pub abis: Vec<ABI>
 }
 
-async fn get_contract(domain: &str, id: &str, by_longest_chain: bool) -> Result<Contract, Error> {
-    let url = format!("{}/getContract/{}/{}", domain, id, by_longest_chain);
-    let req = reqwest::get(&url).await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<Contract>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
-    }
-}
-
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_contract_should_be_ok() {
-        let response = get_contract("http://api.iost.io","base.iost",true).await;
+        let node = MockNode::start();
+        let response = node.client().get_contract("base.iost", true).await;
         assert!(response.is_ok());
     }
 }
@@ -1,4 +1,3 @@
-use crate::error::Error;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize)]
@@ -20,28 +19,14 @@ pub struct ContractStorage {
     /// the hash of block from which the data is from
     pub block_hash: String,
     /// the number of block from which the data is from
-    pub block_number: String
-}
-
-async fn get_contract_storage(new_post: ContractStoragePost) -> Result<ContractStorage, Error>  {
-    let res_status = reqwest::Client::new()
-        .post("http://api.iost.io/getContractStorage")
-        .json(&new_post)
-        .send()
-        .await.map_err(Error::Reqwest)?;
-
-    if res_status.status() == 200 {
-        let response = res_status.json().await.map_err(Error::Reqwest)?;
-        Ok(response)
-    } else {
-        let response = res_status.json().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(response))
-    }
+    #[serde(with = "crate::number::string")]
+    pub block_number: u64
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_contract_storage_should_be_ok () {
@@ -52,7 +37,9 @@ mod test {
             by_longest_chain: true
         };
 
-        let res = get_contract_storage(new_post).await;
+        let node = MockNode::start();
+
+        let res = node.client().get_contract_storage(new_post).await;
         assert!(res.is_ok());
     }
 }
@@ -1,4 +1,3 @@
-use crate::error::Error;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize)]
@@ -18,27 +17,10 @@ pub struct ContractStorageFields {
 
 }
 
-async fn get_contract_storage_fields(new_post: ContractStorageFieldsPost) -> Result<ContractStorageFields, Error> {
-    let req = reqwest::Client::new()
-        .post("http://api.iost.io/getContractStorageFields")
-        .json(&new_post)
-        .send()
-        .await.map_err(Error::Reqwest)?;
-
-    let status_code = req.status();
-
-    if status_code == 200 {
-        let response = req.json().await.map_err(Error::Reqwest)?;
-        Ok(response)
-    } else {
-        let response = req.json().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(response))
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_contract_storage_fields_should_be_ok() {
@@ -48,7 +30,9 @@ mod test {
             by_longest_chain: true
         };
 
-        let res = get_contract_storage_fields(new_post).await;
+        let node = MockNode::start();
+
+        let res = node.client().get_contract_storage_fields(new_post).await;
         assert!(res.is_ok());
     }
 }
@@ -8,22 +8,14 @@ pub struct GasRatio {
     pub median_gas_ratio: f64
 }
 
-async fn get_gas_ratio() -> GasRatio{
-    let res = reqwest::get("https://api.iost.io/getGasRatio").await.unwrap()
-        .json::<GasRatio>()
-        .await.unwrap();
-    res
-}
-
-
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_gas_ratio_should_be_ok() {
-
-        println!("{:#?}", get_gas_ratio().await);
-
+        let node = MockNode::start();
+        let response = node.client().get_gas_ratio().await;
+        assert!(response.is_ok());
     }
 }
@@ -1,8 +1,6 @@
 #![allow(dead_code)]
 
 use crate::net_work_info::NetWork;
-use crate::message::ErrorMessage;
-use crate::error::Error;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -18,29 +16,19 @@ pub struct NodeInfo {
     /// the version of code
     pub code_version: String,
     /// the current timestamp of the server, unit is nano second
-    pub server_time: String,
-}
-
-async fn get_node_info () -> Result<NodeInfo, Error>  {
-    let req = reqwest::get("https://api.iost.io/getNodeInfo").await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<NodeInfo>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
-    }
+    #[serde(with = "crate::number::string")]
+    pub server_time: i64,
 }
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn get_node_info_should_be_ok() {
-
-        println!("{:#?}", get_node_info().await);
-
+        let node = MockNode::start();
+        let response = node.client().get_node_info().await;
+        assert!(response.is_ok());
     }
 }
 
@@ -7,7 +7,8 @@ pub struct Item {
     /// true - id is a key pair; false - id is a permission name
     pub is_key_pair: bool,
     /// permission weight
-    pub weight: String,
+    #[serde(with = "crate::number::string")]
+    pub weight: i64,
     /// the permission
     pub permission: String
 }
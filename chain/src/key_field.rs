@@ -1,6 +1,6 @@
 use serde::{Serialize};
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize, Debug, PartialEq, Eq, Hash)]
 pub struct KeyField {
     /// the key of StateDB
     pub key: String,
@@ -3,72 +3,137 @@
 extern crate alloc;
 
 use async_trait::async_trait;
+use crate::retry::Endpoints;
+use futures::stream::Stream;
+use iost_core::bytes::*;
+use serde::{Serialize, Deserialize};
+
+pub use crate::abi::ABI;
+pub use crate::account_creator::{validate_account_name, AccountCreator};
+pub use crate::amount::Amount;
+pub use crate::block::Block;
+pub use crate::block_header::BlockHeader;
+pub use crate::block_stream::{BlockEvent, BlockId, BlockStream, Checkpoint, Follow};
+pub use crate::confirm::Confirmation;
+pub use crate::contract_client::ContractClient;
+pub use crate::contracts::ContractCall;
 pub use crate::error::Error;
-use crate::bytes::*;
-pub use crate::get_node_info::NodeInfo;
+pub use crate::frozen_balance::FrozenBalance;
+pub use crate::gas_info::GasInfo;
+pub use crate::get_account::Account;
+pub use crate::get_batch_contract_storage::{BatchContractStorage, BatchContractStoragePost};
+pub use crate::get_block_by_hash::{BlockByHash, BlockByNumber};
+pub use crate::get_candidate_bonus::CandidateBonus;
 pub use crate::get_chain_info::ChainInfo;
-pub use crate::get_gas_ratio::GasRatio;
-pub use crate::get_ram_info::RamInfo;
+pub use crate::get_contract::Contract;
 pub use crate::get_contract_storage::{ContractStorage, ContractStoragePost};
 pub use crate::get_contract_storage_fields::{ContractStorageFields, ContractStorageFieldsPost};
-pub use crate::get_batch_contract_storage::{BatchContractStorage, BatchContractStoragePost};
+pub use crate::get_gas_ratio::GasRatio;
+pub use crate::get_node_info::NodeInfo;
+pub use crate::get_producer_vote_info::ProducerVoteInfo;
+pub use crate::get_ram_info::RamInfo;
+pub use crate::get_token_balance::TokenBalance;
+pub use crate::get_token_info::TokenInfo;
+pub use crate::get_tx_by_hash::GetTxByHash;
+pub use crate::get_voter_bonus::VoterBonus;
+pub use crate::group::Group;
+pub use crate::info::Info;
+pub use crate::item::Item;
 pub use crate::key_field::KeyField;
+pub use crate::merkle::{merkle_root, MerkleProof};
 pub use crate::message::ErrorMessage;
-use serde::{Serialize, Deserialize};
-use crate::tx::Tx;
-use crate::tx_response::TxResponse;
+pub use crate::multisig::{PartiallySignedTx, PermissionChecker};
+pub use crate::net_work_info::NetWork;
+pub use crate::number::Decimal;
+pub use crate::permission::Permission;
+pub use crate::pledge_info::PledgeInfo;
+pub use crate::ram_info::RAMInfo;
+pub use crate::receipts::Receipt;
+pub use crate::retry::{EndpointHealth, RetryPolicy};
+pub use crate::status::Status;
+pub use crate::status_code::StatusCode;
+pub use crate::storage::{StorageReader, Stored};
+pub use crate::subscribe::{Event, SubscribeFilter, SubscribePost, Topic};
+pub use crate::transaction::Transaction;
+pub use crate::transport::{BodyStream, HttpTransport, Method, Request, Response, StreamingResponse, Transport};
+pub use crate::tx_builder::TxBuilder;
+pub use crate::tx_receipt::TxReceipt;
+pub use crate::tx_response::TxResponse;
+pub use crate::vote_info::VoteInfo;
+pub use iost_core::Action;
+pub use iost_core::AmountLimit;
+pub use iost_core::Signature;
+pub use iost_core::Tx;
+pub use iost_core::{ReadError, WriteError};
 
 mod abi;
-mod action;
-mod amount_limit;
+mod account_creator;
+mod amount;
 mod block;
-mod bytes;
+mod block_header;
+mod block_stream;
+mod confirm;
+mod contract_client;
+pub mod contracts;
 mod error;
 mod frozen_balance;
 mod gas_info;
-mod get_block_by_hash;
+mod get_account;
 mod get_batch_contract_storage;
+mod get_block_by_hash;
+mod get_candidate_bonus;
+mod get_chain_info;
+mod get_contract;
 mod get_contract_storage;
 mod get_contract_storage_fields;
-mod get_chain_info;
-mod get_node_info;
-mod net_work_info;
 mod get_gas_ratio;
+mod get_node_info;
+mod get_producer_vote_info;
 mod get_ram_info;
-mod get_tx_by_hash;
 mod get_token_balance;
-mod get_producer_vote_info;
-mod get_contract;
-mod get_candidate_bonus;
-mod get_voter_bonus;
 mod get_token_info;
-mod get_account;
+mod get_tx_by_hash;
+mod get_voter_bonus;
 mod group;
 mod info;
 mod item;
 mod key_field;
+pub mod merkle;
 mod message;
-mod pledge_info;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+mod multisig;
+mod net_work_info;
+mod number;
 mod permission;
+mod pledge_info;
 mod ram_info;
 mod receipts;
+mod retry;
 mod status;
 mod status_code;
-mod signature;
+mod storage;
+mod subscribe;
 mod transaction;
-mod tx;
+pub mod transport;
+mod tx_builder;
 mod tx_receipt;
 mod tx_response;
-mod unsigned_int;
 mod vote_info;
 
-struct IOST {
-    host: String,
-    client: reqwest::Client,
+/// Client of the IOST HTTP API served by one or more nodes
+///
+/// Requests go to the healthiest endpoint. Transport errors and 5xx responses that the node
+/// does not explain with a classified error are retried on the next endpoint after a backoff.
+/// Requests are carried by `T`, HTTP over `reqwest` unless another `Transport` is given.
+pub struct IOST<T = HttpTransport> {
+    endpoints: Endpoints,
+    transport: T,
+    retry_policy: RetryPolicy,
 }
 
 #[async_trait]
-trait Client {
+pub trait Client {
     fn new(host: &str) -> Self;
 
     async fn get<T>(&self, path: &str) -> Result<T, Error> where T: 'static + for<'de>Deserialize<'de>;
@@ -79,49 +144,152 @@ trait Client {
 }
 
 #[async_trait]
-impl Client for IOST {
+impl<C: Transport + Default> Client for IOST<C> {
 
     fn new(host: &str) -> Self {
-        Self {
-            host: host.to_owned(),
-            client: reqwest::Client::new()
-        }
+        IOST::with_transport(C::default(), &[host])
     }
 
     async fn get<T>(&self, path: &str) -> Result<T, Error> where T: 'static + for<'de>Deserialize<'de> {
-        let url = format!("{}/{}", self.host, path);
-        let response = self.client.get(&url).send().await.map_err(Error::Reqwest)?;
-        if response.status() == 200 {
-            let result = response.json::<T>().await.map_err(Error::Reqwest)?;
-            Ok(result)
-        } else {
-            let rsp = response.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-            Err(Error::ErrorMessage(rsp))
-        }
+        IOST::get(self, path).await
     }
 
     async fn post<T, R>(&self, path: &str, param: R) -> Result<T, Error>
         where T: 'static + for<'de> Deserialize<'de>,
               R: Serialize + Send + Sync
     {
-        let url = format!("{}/{}", self.host, path);
-        let req = reqwest::Client::new()
-            .post(&url)
-            .json(&param)
-            .send()
-            .await.map_err(Error::Reqwest)?;
-        let code_status = req.status();
-        if code_status == 200 {
-            let response = req.json().await.map_err(Error::Reqwest)?;
-            Ok(response)
-        } else {
-            let response = req.json().await.map_err(Error::Reqwest)?;
-            Err(Error::ErrorMessage(response))
-        }
+        IOST::post(self, path, param).await
     }
 }
 
+/// Outcome of a single attempt of a request
+enum Attempt<T> {
+    Done(Result<T, Error>),
+    Retry(Error),
+}
+
 impl IOST {
+    /// A client of the node at `host` over HTTP
+    pub fn new(host: &str) -> Self {
+        IOST::with_endpoints(&[host])
+    }
+
+    /// A client failing over between `hosts`, in order of preference
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hosts` is empty.
+    pub fn with_endpoints(hosts: &[&str]) -> Self {
+        IOST::with_transport(HttpTransport::new(), hosts)
+    }
+}
+
+impl<T: Transport> IOST<T> {
+    /// A client sending its requests over `transport`, failing over between `hosts` in order
+    /// of preference
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hosts` is empty.
+    pub fn with_transport(transport: T, hosts: &[&str]) -> Self {
+        assert!(!hosts.is_empty(), "IOST client needs at least one endpoint");
+        IOST {
+            endpoints: Endpoints::new(hosts.iter().map(|host| (*host).to_owned()).collect()),
+            transport,
+            retry_policy: RetryPolicy::default()
+        }
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Consecutive failures of every endpoint, in configured order
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// Base url of the endpoint the next request goes to
+    pub fn host(&self) -> &str {
+        self.endpoints.host(self.endpoints.order()[0])
+    }
+
+    /// The transport requests are sent over
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub async fn get<R>(&self, path: &str) -> Result<R, Error> where R: 'static + for<'de>Deserialize<'de> {
+        self.request(path, None).await
+    }
+
+    pub async fn post<R, P>(&self, path: &str, param: P) -> Result<R, Error>
+        where R: 'static + for<'de> Deserialize<'de>,
+              P: Serialize
+    {
+        let param = serde_json::to_vec(&param).map_err(Error::Json)?;
+        self.request(path, Some(param)).await
+    }
+
+    async fn request<R>(&self, path: &str, param: Option<Vec<u8>>) -> Result<R, Error>
+        where R: 'static + for<'de> Deserialize<'de>
+    {
+        self.request_retried(path, param).await.0
+    }
+
+    /// `request`, along with whether the result came from a retry
+    async fn request_retried<R>(&self, path: &str, param: Option<Vec<u8>>) -> (Result<R, Error>, bool)
+        where R: 'static + for<'de> Deserialize<'de>
+    {
+        let order = self.endpoints.order();
+        let mut retry = 0;
+        loop {
+            let endpoint = order[retry as usize % order.len()];
+            let url = format!("{}/{}", self.endpoints.host(endpoint), path);
+            let request = match &param {
+                Some(param) => Request::post(url, param.clone()),
+                None => Request::get(url),
+            };
+            let error = match self.attempt(request).await {
+                Attempt::Done(result) => {
+                    self.endpoints.succeeded(endpoint);
+                    return (result, retry > 0);
+                }
+                Attempt::Retry(error) => error,
+            };
+            self.endpoints.failed(endpoint);
+            if retry >= self.retry_policy.max_retries {
+                return (Err(error), retry > 0);
+            }
+            retry += 1;
+            tokio::time::delay_for(self.retry_policy.backoff(retry)).await;
+        }
+    }
+
+    async fn attempt<R>(&self, request: Request) -> Attempt<R>
+        where R: 'static + for<'de> Deserialize<'de>
+    {
+        let response = match self.transport.send(request).await {
+            Ok(response) => response,
+            Err(e) => return Attempt::Retry(e),
+        };
+        if response.status == 200 {
+            return Attempt::Done(serde_json::from_slice::<R>(&response.body).map_err(Error::Json));
+        }
+        let body = String::from_utf8_lossy(&response.body).into_owned();
+        let message = serde_json::from_str::<ErrorMessage>(&body).unwrap_or(ErrorMessage {
+            code: i32::from(response.status),
+            message: body
+        });
+        match Error::from(message) {
+            error @ Error::ErrorMessage(_) if response.status >= 500 => Attempt::Retry(error),
+            error => Attempt::Done(Err(error)),
+        }
+    }
+}
+
+impl<T: Transport> IOST<T> {
     pub async fn get_node_info(&self) -> Result<NodeInfo, Error> {
         self.get("getNodeInfo").await
     }
@@ -150,8 +318,79 @@ impl IOST {
         self.post("getBatchContractStorage", &par).await
     }
 
+    /// Send a signed transaction. Retrying is safe: the node identifies a transaction by its hash
+    /// and never executes it twice, so a `DuplicateTx` error after a retry means an earlier
+    /// attempt was accepted and the response carries the locally computed hash.
     pub async fn send_tx(&self, par: Tx) -> Result<TxResponse, Error> {
-        self.post("sendTx", par).await
+        let param = serde_json::to_vec(&par).map_err(Error::Json)?;
+        match self.request_retried("sendTx", Some(param)).await {
+            (Err(Error::DuplicateTx(_)), true) => Ok(TxResponse {
+                hash: iost_keys::base58::encode_slice(&par.hash()?),
+                pre_tx_receipt: None
+            }),
+            (result, _) => result,
+        }
+    }
+
+    /// Execute a transaction on the node without broadcasting it, to learn its gas and RAM usage
+    pub async fn exec_tx(&self, par: &Tx) -> Result<TxReceipt, Error> {
+        self.post("execTx", par).await
+    }
+
+    /// Stream the events of `topics`, optionally only those of the contract `contract_id`.
+    /// The subscription reconnects when the node drops the stream.
+    pub fn subscribe(&self, topics: Vec<Topic>, contract_id: Option<&str>) -> impl Stream<Item = Result<Event, Error>>
+        where T: Clone + 'static
+    {
+        let param = SubscribePost {
+            topics,
+            filter: contract_id.map(|id| SubscribeFilter { contract_id: id.to_owned() })
+        };
+        subscribe::subscribe(self.transport.clone(), format!("{}/subscribe", self.host()), param, subscribe::RECONNECT_DELAY)
+    }
+
+    pub async fn get_account(&self, name: &str, by_longest_chain: bool) -> Result<Account, Error> {
+        self.get(&format!("getAccount/{}/{}", name, by_longest_chain)).await
+    }
+
+    pub async fn get_block_by_hash(&self, hash: &str, complete: bool) -> Result<BlockByHash, Error> {
+        self.get(&format!("getBlockByHash/{}/{}", hash, complete)).await
+    }
+
+    pub async fn get_block_by_number(&self, number: u64, complete: bool) -> Result<BlockByNumber, Error> {
+        self.get(&format!("getBlockByNumber/{}/{}", number, complete)).await
+    }
+
+    pub async fn get_tx_by_hash(&self, hash: &str) -> Result<GetTxByHash, Error> {
+        self.get(&format!("getTxByHash/{}", hash)).await
+    }
+
+    pub async fn get_tx_receipt_by_tx_hash(&self, hash: &str) -> Result<TxReceipt, Error> {
+        self.get(&format!("getTxReceiptByTxHash/{}", hash)).await
+    }
+
+    pub async fn get_contract(&self, id: &str, by_longest_chain: bool) -> Result<Contract, Error> {
+        self.get(&format!("getContract/{}/{}", id, by_longest_chain)).await
+    }
+
+    pub async fn get_token_balance(&self, account: &str, token: &str, by_longest_chain: bool) -> Result<TokenBalance, Error> {
+        self.get(&format!("getTokenBalance/{}/{}/{}", account, token, by_longest_chain)).await
+    }
+
+    pub async fn get_token_info(&self, symbol: &str, by_longest_chain: bool) -> Result<TokenInfo, Error> {
+        self.get(&format!("getTokenInfo/{}/{}", symbol, by_longest_chain)).await
+    }
+
+    pub async fn get_producer_vote_info(&self, id: &str, by_longest_chain: bool) -> Result<ProducerVoteInfo, Error> {
+        self.get(&format!("getProducerVoteInfo/{}/{}", id, by_longest_chain)).await
+    }
+
+    pub async fn get_candidate_bonus(&self, name: &str, by_longest_chain: bool) -> Result<CandidateBonus, Error> {
+        self.get(&format!("getCandidateBonus/{}/{}", name, by_longest_chain)).await
+    }
+
+    pub async fn get_voter_bonus(&self, name: &str, by_longest_chain: bool) -> Result<VoterBonus, Error> {
+        self.get(&format!("getVoterBonus/{}/{}", name, by_longest_chain)).await
     }
 }
 
@@ -159,14 +398,12 @@ impl IOST {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::amount_limit::AmountLimit;
-    use crate::signature::Signature;
-    use crate::action::Action;
+    use crate::mock::MockNode;
 
     #[tokio::test]
     async fn iost_basic_get_method_should_be_ok() {
-        let host = "https://api.iost.io";
-        let iost = IOST::new(host);
+        let node = MockNode::start();
+        let iost = node.client();
         let result = iost.get_node_info().await;
         assert!(result.is_ok());
         let chain_result = iost.get_chain_info().await;
@@ -177,10 +414,99 @@ mod tests {
         assert!(ram_result.is_ok());
     }
 
+    fn fast_retries(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: std::time::Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn iost_should_fail_over_to_healthy_endpoint() {
+        let down = MockNode::empty();
+        down.respond_with("getChainInfo", 503, "upstream unavailable");
+        let up = MockNode::start();
+        let iost = IOST::with_endpoints(&[&down.host(), &up.host()]).retry_policy(fast_retries(1));
+        assert!(iost.get_chain_info().await.is_ok());
+        assert_eq!(iost.endpoint_health()[0].consecutive_failures, 1);
+        assert_eq!(iost.endpoint_health()[1].consecutive_failures, 0);
+        // the failing endpoint is now tried last
+        assert_eq!(iost.host(), up.host());
+        assert!(iost.get_chain_info().await.is_ok());
+        assert_eq!(down.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn iost_should_retry_server_errors_only() {
+        let node = MockNode::start();
+        node.respond_error("getGasRatio", 500, 2, "internal error");
+        let iost = IOST::new(&node.host()).retry_policy(fast_retries(2));
+        assert!(iost.get_gas_ratio().await.is_err());
+        assert_eq!(node.requests().len(), 3);
+
+        // classified errors are answers of the node and are not retried
+        node.respond_error("getRAMInfo", 500, 2, "tx exists in pending");
+        assert!(matches!(iost.get_ram_info().await, Err(Error::DuplicateTx(_))));
+        assert_eq!(node.requests().len(), 4);
+        assert!(matches!(iost.get_producer_vote_info("producerName", true).await, Err(Error::NotFound(_))));
+        assert_eq!(node.requests().len(), 5);
+    }
+
+    /// Fails every request to `down`, sends the others through `inner`
+    struct Unreachable<T> {
+        down: &'static str,
+        inner: T,
+    }
+
+    #[async_trait]
+    impl<T: Transport> Transport for Unreachable<T> {
+        async fn send(&self, request: Request) -> Result<Response, Error> {
+            if request.url.starts_with(self.down) {
+                let e = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+                return Err(Error::Transport(Box::new(e)));
+            }
+            self.inner.send(request).await
+        }
+    }
+
+    /// Refuses every request
+    #[derive(Default)]
+    struct Refused;
+
+    #[async_trait]
+    impl Transport for Refused {
+        async fn send(&self, _request: Request) -> Result<Response, Error> {
+            let e = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+            Err(Error::Transport(Box::new(e)))
+        }
+    }
+
+    #[tokio::test]
+    async fn client_should_build_any_default_transport() {
+        let iost = <IOST<Refused> as Client>::new("http://down").retry_policy(RetryPolicy::none());
+        assert_eq!(iost.host(), "http://down");
+        let result: Result<ChainInfo, Error> = Client::get(&iost, "getChainInfo").await;
+        assert!(matches!(result, Err(Error::Transport(_))));
+    }
+
+    #[tokio::test]
+    async fn iost_should_retry_transport_errors() {
+        let node = MockNode::start();
+        let transport = Unreachable { down: "http://down", inner: node.transport() };
+        let iost = IOST::with_transport(transport, &["http://down", "http://up"]).retry_policy(fast_retries(1));
+        assert_eq!(iost.get_chain_info().await.unwrap().chain_id, 1024);
+        assert_eq!(iost.endpoint_health()[0].consecutive_failures, 1);
+        assert_eq!(node.requests().len(), 1);
+
+        let iost = IOST::with_transport(iost.transport, &["http://down"]).retry_policy(RetryPolicy::none());
+        assert!(matches!(iost.get_chain_info().await, Err(Error::Transport(_))));
+    }
+
     #[tokio::test]
     async fn iost_basic_post_method_should_be_ok() {
-        let host = "https://api.iost.io";
-        let iost = IOST::new(host);
+        let node = MockNode::start();
+        let iost = node.client();
         let new_post = ContractStoragePost {
             id: "token.iost".to_string(),
             key: "TIiost".to_string(),
@@ -217,13 +543,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_send_tx_should_be_ok() {
-        let host = "https://api.iost.io";
-        let iost = IOST::new(host);
-        let action = Action{
-            contract: "token.iost".to_string(),
-            action_name: "transfer".to_string(),
-            data: "[\"iost\", \"testaccount\", \"anothertest\", \"100\", \"this is an example transfer\"]".to_string()
-        };
+        let node = MockNode::start();
+        let iost = node.client();
+        let amount = Amount::parse("100", 8, "iost").unwrap();
+        let action = contracts::token::transfer(&amount, "testaccount", "anothertest", "this is an example transfer").action;
 
         let amount_limit = AmountLimit{
             token: "*".to_string(),
@@ -252,7 +575,30 @@ mod tests {
         };
 
         let tx_result = iost.send_tx(tx).await;
-        dbg!(tx_result);
+        assert!(tx_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_tx_should_accept_duplicate_after_retry() {
+        let node = MockNode::start();
+        node.respond_error("sendTx", 400, 2, "tx exists in pending");
+        let tx = Tx {
+            gas_ratio: 1.0,
+            gas_limit: 500000.0,
+            chain_id: 1024,
+            publisher: "testaccount".to_string(),
+            ..Default::default()
+        };
+
+        // the first attempt fails as if its answer was lost, the retry finds the transaction sent
+        let transport = Unreachable { down: "http://down", inner: node.transport() };
+        let iost = IOST::with_transport(transport, &["http://down", "http://up"]).retry_policy(fast_retries(1));
+        let response = iost.send_tx(tx.clone()).await.unwrap();
+        assert_eq!(response.hash, iost_keys::base58::encode_slice(&tx.hash().unwrap()));
+        assert!(response.pre_tx_receipt.is_none());
+
+        let iost = IOST::with_transport(node.transport(), &["http://up"]);
+        assert!(matches!(iost.send_tx(tx).await, Err(Error::DuplicateTx(_))));
     }
 }
 
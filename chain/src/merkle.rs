@@ -0,0 +1,318 @@
+//! IOST merkle trees of block transactions and receipts
+//!
+//! Roots follow `MerkleTree.Build` of go-iost `core/merkletree/merkle_tree.go`, which
+//! `Block.CalculateTxMerkleHash` and `Block.CalculateTxReceiptMerkleHash` call with the hashes of
+//! the transactions and receipts of a block:
+//!
+//! - the leaves fill a tree of at least two slots, padded to a power of two with empty slots;
+//! - the parent of two nodes is the SHA3-256 of their concatenation;
+//! - the parent of a node and an empty slot is the SHA3-256 of the node alone, so a trailing odd
+//!   node, and a single leaf, is hashed once more rather than carried up;
+//! - a block without transactions has an empty root.
+//!
+//! Transaction leaves are the transaction hashes, receipt leaves are `TxReceipt::hash`.
+
+use crate::block::Block;
+use crate::error::Error;
+use crate::transaction::Transaction;
+use iost_core::sha3_256;
+use crate::tx_receipt::TxReceipt;
+use serde::{Serialize, Deserialize};
+
+pub type Hash = [u8; 32];
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(left);
+    bytes.extend_from_slice(right);
+    sha3_256(&bytes)
+}
+
+fn next_level(level: &[Hash]) -> Vec<Hash> {
+    level.chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_pair(left, right),
+            [single] => sha3_256(single),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root of the tree over `leaves`, `None` for no leaves
+pub fn merkle_root(leaves: &[Hash]) -> Option<Hash> {
+    if leaves.is_empty() {
+        return None;
+    }
+    let mut level = next_level(leaves);
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    Some(level[0])
+}
+
+/// Proof that a leaf is part of a merkle tree: the sibling at every level from the leaf up,
+/// `None` where the node had no sibling and was hashed alone
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// position of the leaf
+    pub index: usize,
+    /// siblings from the leaf level up to below the root
+    pub siblings: Vec<Option<Hash>>
+}
+
+impl MerkleProof {
+    /// Proof for the leaf at `index`, `None` when it is out of range
+    pub fn new(leaves: &[Hash], index: usize) -> Option<Self> {
+        if index >= leaves.len() {
+            return None;
+        }
+        let mut siblings = Vec::new();
+        let mut level = leaves.to_vec();
+        let mut i = index;
+        loop {
+            siblings.push(level.get(i ^ 1).copied());
+            level = next_level(&level);
+            i /= 2;
+            if level.len() == 1 {
+                break;
+            }
+        }
+        Some(MerkleProof { index, siblings })
+    }
+
+    /// Root of the tree the proof leads to from `leaf`
+    pub fn root(&self, leaf: &Hash) -> Hash {
+        let mut hash = *leaf;
+        for (level, sibling) in self.siblings.iter().enumerate() {
+            hash = match sibling {
+                Some(sibling) if (self.index >> level) & 1 == 0 => hash_pair(&hash, sibling),
+                Some(sibling) => hash_pair(sibling, &hash),
+                None => sha3_256(&hash),
+            };
+        }
+        hash
+    }
+
+    pub fn verify(&self, leaf: &Hash, root: &Hash) -> bool {
+        self.root(leaf) == *root
+    }
+
+    /// Whether `transaction` is part of a block whose `tx_merkle_hash` is `root`
+    pub fn verify_tx(&self, transaction: &Transaction, root: &str) -> Result<bool, Error> {
+        Ok(self.verify(&decode_hash(&transaction.hash)?, &decode_hash(root)?))
+    }
+
+    /// Whether `receipt` is part of a block whose `tx_receipt_merkle_hash` is `root`
+    pub fn verify_receipt(&self, receipt: &TxReceipt, root: &str) -> Result<bool, Error> {
+        Ok(self.verify(&receipt.hash()?, &decode_hash(root)?))
+    }
+}
+
+/// Decode a base58 hash of the IOST API
+pub fn decode_hash(hash: &str) -> Result<Hash, Error> {
+    let bytes = iost_keys::base58::from(hash)
+        .map_err(|_| Error::InvalidBlock(format!("{} is not a base58 hash", hash)))?;
+    if bytes.len() != 32 {
+        return Err(Error::InvalidBlock(format!("{} is not a 32 byte hash", hash)));
+    }
+    let mut decoded = [0u8; 32];
+    decoded.copy_from_slice(&bytes);
+    Ok(decoded)
+}
+
+fn complete_transactions(block: &Block) -> Result<&[Transaction], Error> {
+    if block.transactions.len() as u64 != block.tx_count {
+        return Err(Error::InvalidBlock(format!("block {} has {} of its {} transactions, fetch it with complete=true",
+            block.number, block.transactions.len(), block.tx_count)));
+    }
+    Ok(&block.transactions)
+}
+
+/// Transaction leaves of a complete block
+pub fn tx_leaves(block: &Block) -> Result<Vec<Hash>, Error> {
+    complete_transactions(block)?.iter().map(|tx| decode_hash(&tx.hash)).collect()
+}
+
+/// Receipt leaves of a complete block
+pub fn receipt_leaves(block: &Block) -> Result<Vec<Hash>, Error> {
+    complete_transactions(block)?.iter().map(|tx| tx.tx_receipt.hash()).collect()
+}
+
+fn root_matches(leaves: &[Hash], root: &str) -> Result<bool, Error> {
+    match merkle_root(leaves) {
+        Some(computed) => Ok(!root.is_empty() && decode_hash(root)? == computed),
+        None => Ok(root.is_empty()),
+    }
+}
+
+impl Block {
+    /// Recompute `tx_merkle_hash` and `tx_receipt_merkle_hash` from the transactions of a block
+    /// fetched with `complete=true`, failing with `InvalidBlock` when either does not match
+    pub fn verify_merkle_roots(&self) -> Result<(), Error> {
+        if !root_matches(&tx_leaves(self)?, &self.tx_merkle_hash)? {
+            return Err(Error::InvalidBlock(format!("tx_merkle_hash of block {} does not match its transactions", self.number)));
+        }
+        if !root_matches(&receipt_leaves(self)?, &self.tx_receipt_merkle_hash)? {
+            return Err(Error::InvalidBlock(format!("tx_receipt_merkle_hash of block {} does not match its receipts", self.number)));
+        }
+        Ok(())
+    }
+
+    /// Inclusion proof of the transaction `tx_hash` against `tx_merkle_hash`
+    pub fn tx_proof(&self, tx_hash: &str) -> Result<MerkleProof, Error> {
+        let leaves = tx_leaves(self)?;
+        MerkleProof::new(&leaves, self.tx_index(tx_hash)?).ok_or_else(|| Error::InvalidBlock(tx_hash.to_owned()))
+    }
+
+    /// Inclusion proof of the receipt of `tx_hash` against `tx_receipt_merkle_hash`
+    pub fn receipt_proof(&self, tx_hash: &str) -> Result<MerkleProof, Error> {
+        let leaves = receipt_leaves(self)?;
+        MerkleProof::new(&leaves, self.tx_index(tx_hash)?).ok_or_else(|| Error::InvalidBlock(tx_hash.to_owned()))
+    }
+
+    fn tx_index(&self, tx_hash: &str) -> Result<usize, Error> {
+        self.transactions.iter()
+            .position(|tx| tx.hash == tx_hash)
+            .ok_or_else(|| Error::InvalidBlock(format!("transaction {} is not in block {}", tx_hash, self.number)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::fixture_block;
+
+    fn leaf(n: u8) -> Hash {
+        sha3_256(&[n])
+    }
+
+    fn encode(hash: &Hash) -> String {
+        iost_keys::base58::encode_slice(hash)
+    }
+
+    /// The fixture block with its single transaction repeated `count` times under distinct hashes
+    fn block_of(count: u8) -> Block {
+        fixture_block(|block| {
+            let tx = block["transactions"][0].clone();
+            let transactions: Vec<serde_json::Value> = (0..count).map(|n| {
+                let mut tx = tx.clone();
+                tx["hash"] = serde_json::json!(encode(&leaf(n)));
+                tx["tx_receipt"]["tx_hash"] = tx["hash"].clone();
+                tx
+            }).collect();
+            block["tx_count"] = serde_json::json!(count.to_string());
+            block["transactions"] = serde_json::json!(transactions);
+        })
+    }
+
+    /// Root of three leaves spelled out level by level, independent of `merkle_root`
+    fn root_of_three(leaves: &[Hash]) -> String {
+        encode(&hash_pair(&hash_pair(&leaves[0], &leaves[1]), &sha3_256(&leaves[2])))
+    }
+
+    /// A block of three transactions with the roots go-iost computes for them
+    fn complete_block() -> Block {
+        let mut block = block_of(3);
+        block.tx_merkle_hash = root_of_three(&tx_leaves(&block).unwrap());
+        block.tx_receipt_merkle_hash = root_of_three(&receipt_leaves(&block).unwrap());
+        block
+    }
+
+    #[test]
+    fn merkle_root_should_hash_odd_nodes_alone() {
+        assert_eq!(merkle_root(&[]), None);
+        assert_eq!(merkle_root(&[leaf(0)]), Some(sha3_256(&leaf(0))));
+        let ab = hash_pair(&leaf(0), &leaf(1));
+        let cd = hash_pair(&leaf(2), &leaf(3));
+        assert_eq!(merkle_root(&[leaf(0), leaf(1), leaf(2)]), Some(hash_pair(&ab, &sha3_256(&leaf(2)))));
+        let leaves: Vec<Hash> = (0..5).map(leaf).collect();
+        assert_eq!(merkle_root(&leaves), Some(hash_pair(&hash_pair(&ab, &cd), &sha3_256(&sha3_256(&leaf(4))))));
+    }
+
+    #[test]
+    fn merkle_root_should_match_go_iost_vectors() {
+        let vectors = [
+            (1, "6reAKJCubGtWPLLBofWzQR4XprYgH47rFShx13EQ1wQC"),
+            (2, "FebtAGB8LJMyfA1XSqb7BQairPdbBcm3iuA6biBFCUhh"),
+            (3, "4fH6LhAYRwXM7JcymanuAvxXdW7mG8HNRxMGCfxUEa3T"),
+            (5, "2ZXBc6RsB8gF9SqKTohJabm1jyaVt8E1iFRp1HAMre8r"),
+        ];
+        for (count, root) in vectors.iter() {
+            let leaves: Vec<Hash> = (0..*count).map(leaf).collect();
+            assert_eq!(encode(&merkle_root(&leaves).unwrap()), *root, "{} leaves", count);
+        }
+    }
+
+    #[test]
+    fn merkle_proof_should_verify_every_leaf() {
+        for count in 1..=9u8 {
+            let leaves: Vec<Hash> = (0..count).map(leaf).collect();
+            let root = merkle_root(&leaves).unwrap();
+            for (i, l) in leaves.iter().enumerate() {
+                let proof = MerkleProof::new(&leaves, i).unwrap();
+                assert!(proof.verify(l, &root), "leaf {} of {}", i, count);
+                assert!(!proof.verify(&leaf(100), &root));
+            }
+            assert!(MerkleProof::new(&leaves, count as usize).is_none());
+        }
+    }
+
+    #[test]
+    fn block_merkle_roots_should_verify() {
+        let block = complete_block();
+        assert!(block.verify_merkle_roots().is_ok());
+        let mut empty = block_of(0);
+        empty.tx_merkle_hash.clear();
+        empty.tx_receipt_merkle_hash.clear();
+        assert!(empty.verify_merkle_roots().is_ok());
+
+        let tx_hash = block.transactions[2].hash.clone();
+        let proof = block.tx_proof(&tx_hash).unwrap();
+        assert!(proof.verify_tx(&block.transactions[2], &block.tx_merkle_hash).unwrap());
+        assert!(!proof.verify_tx(&block.transactions[1], &block.tx_merkle_hash).unwrap());
+        let proof = block.receipt_proof(&tx_hash).unwrap();
+        assert!(proof.verify_receipt(&block.transactions[2].tx_receipt, &block.tx_receipt_merkle_hash).unwrap());
+        assert!(block.tx_proof("unknown").is_err());
+    }
+
+    #[test]
+    fn tampered_block_should_fail_verification() {
+        let mut tampered = complete_block();
+        tampered.transactions[1].tx_receipt.gas_usage += 1.0;
+        assert!(matches!(tampered.verify_merkle_roots(), Err(Error::InvalidBlock(_))));
+
+        let mut tampered = complete_block();
+        tampered.transactions.swap(0, 1);
+        assert!(matches!(tampered.verify_merkle_roots(), Err(Error::InvalidBlock(_))));
+
+        let mut incomplete = complete_block();
+        incomplete.transactions.clear();
+        assert!(matches!(incomplete.verify_merkle_roots(), Err(Error::InvalidBlock(_))));
+    }
+
+    /// Checks the last irreversible blocks of mainnet with at least three transactions as the
+    /// node serves them. Needs access to api.iost.io: `cargo test mainnet -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn mainnet_blocks_should_verify() {
+        let iost = crate::IOST::new("https://api.iost.io");
+        let lib_block = iost.get_chain_info().await.unwrap().lib_block;
+        let mut verified = 0;
+        for number in (lib_block.saturating_sub(1000)..=lib_block).rev() {
+            let block = iost.get_block_by_number(number, true).await.unwrap().block;
+            if block.tx_count < 3 {
+                continue;
+            }
+            block.verify_merkle_roots().unwrap();
+            for tx in &block.transactions {
+                assert!(block.tx_proof(&tx.hash).unwrap().verify_tx(tx, &block.tx_merkle_hash).unwrap());
+                assert!(block.receipt_proof(&tx.hash).unwrap().verify_receipt(&tx.tx_receipt, &block.tx_receipt_merkle_hash).unwrap());
+            }
+            verified += 1;
+            if verified == 3 {
+                return;
+            }
+        }
+        panic!("{} of the last 1000 blocks have three or more transactions", verified);
+    }
+}
@@ -0,0 +1,344 @@
+//! In-process IOST node for offline tests, enabled by the `mock` feature.
+//!
+//! The node answers every endpoint of the `IOST` client with recorded fixtures from `fixtures/`.
+//! Responses can be replaced per endpoint or per full path, including `ErrorMessage` error bodies.
+//! The same responses are also served in memory by `MockTransport`, without going through HTTP.
+
+use crate::block::Block;
+use crate::error::Error;
+use crate::message::ErrorMessage;
+use crate::transport::{self, Method, Transport};
+use crate::IOST;
+use async_trait::async_trait;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+/// Recorded responses of `api.iost.io`: path, HTTP status and body
+pub const FIXTURES: &[(&str, u16, &str)] = &[
+    ("getNodeInfo", 200, include_str!("../fixtures/getNodeInfo.json")),
+    ("getChainInfo", 200, include_str!("../fixtures/getChainInfo.json")),
+    ("getGasRatio", 200, include_str!("../fixtures/getGasRatio.json")),
+    ("getRAMInfo", 200, include_str!("../fixtures/getRAMInfo.json")),
+    ("getAccount", 200, include_str!("../fixtures/getAccount.json")),
+    ("getBlockByHash", 200, include_str!("../fixtures/getBlockByHash.json")),
+    ("getBlockByNumber", 200, include_str!("../fixtures/getBlockByNumber.json")),
+    ("getTxByHash", 200, include_str!("../fixtures/getTxByHash.json")),
+    ("getTxReceiptByTxHash", 200, include_str!("../fixtures/getTxReceiptByTxHash.json")),
+    ("getContract", 200, include_str!("../fixtures/getContract.json")),
+    ("getTokenBalance", 200, include_str!("../fixtures/getTokenBalance.json")),
+    ("getTokenInfo", 200, include_str!("../fixtures/getTokenInfo.json")),
+    ("getProducerVoteInfo", 200, include_str!("../fixtures/getProducerVoteInfo.json")),
+    ("getProducerVoteInfo/producerName", 400, include_str!("../fixtures/getProducerVoteInfo_error.json")),
+    ("getCandidateBonus", 200, include_str!("../fixtures/getCandidateBonus.json")),
+    ("getVoterBonus", 200, include_str!("../fixtures/getVoterBonus.json")),
+    ("getContractStorage", 200, include_str!("../fixtures/getContractStorage.json")),
+    ("getContractStorageFields", 200, include_str!("../fixtures/getContractStorageFields.json")),
+    ("getBatchContractStorage", 200, include_str!("../fixtures/getBatchContractStorage.json")),
+    ("sendTx", 200, include_str!("../fixtures/sendTx.json")),
+    ("execTx", 200, include_str!("../fixtures/execTx.json")),
+    ("subscribe", 200, include_str!("../fixtures/subscribe.json")),
+];
+
+/// The block of the `getBlockByNumber` fixture after `patch` edits its JSON, e.g. its `number`,
+/// `hash` or `transactions`
+pub fn fixture_block<F: FnOnce(&mut serde_json::Value)>(patch: F) -> Block {
+    let mut response: serde_json::Value = serde_json::from_str(include_str!("../fixtures/getBlockByNumber.json"))
+        .expect("getBlockByNumber fixture is JSON; qed");
+    patch(&mut response["block"]);
+    serde_json::from_value(response["block"].take()).expect("patched fixture block deserializes")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// response body, usually JSON
+    pub body: String
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockRequest {
+    /// HTTP method
+    pub method: String,
+    /// path without the leading slash, such as `getAccount/admin/true`
+    pub path: String,
+    /// request body
+    pub body: String
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, MockResponse>,
+    requests: Vec<MockRequest>,
+}
+
+impl State {
+    /// Record a request and answer it
+    fn handle(&mut self, method: String, path: String, body: &[u8]) -> MockResponse {
+        let response = self.response(&path);
+        self.requests.push(MockRequest {
+            method,
+            path,
+            body: String::from_utf8_lossy(body).into_owned()
+        });
+        response
+    }
+
+    /// The response of the longest route that is a prefix of `path` by whole segments
+    fn response(&self, path: &str) -> MockResponse {
+        let mut route = path;
+        loop {
+            if let Some(response) = self.routes.get(route) {
+                return response.clone();
+            }
+            match route.rfind('/') {
+                Some(end) => route = &route[..end],
+                None => return not_found(path),
+            }
+        }
+    }
+}
+
+fn not_found(path: &str) -> MockResponse {
+    let message = ErrorMessage {
+        code: 5,
+        message: format!("no mock response for {}", path)
+    };
+    MockResponse {
+        status: 404,
+        body: serde_json::to_string(&message).expect("ErrorMessage serializes; qed")
+    }
+}
+
+/// A mock IOST node listening on a random local port until it is dropped
+pub struct MockNode {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+impl MockNode {
+    /// Start a node answering with the recorded `FIXTURES`. Must be called within a tokio runtime.
+    pub fn start() -> Self {
+        let node = MockNode::empty();
+        for (path, status, body) in FIXTURES {
+            node.respond_with(path, *status, body);
+        }
+        node
+    }
+
+    /// Start a node without any response; every request is answered with a 404 `ErrorMessage`
+    pub fn empty() -> Self {
+        let state = Arc::new(Mutex::new(State::default()));
+        let service_state = state.clone();
+        let make_svc = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| handle(state.clone(), req)))
+            }
+        });
+        let (shutdown, signal) = tokio::sync::oneshot::channel::<()>();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+        let addr = server.local_addr();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            signal.await.ok();
+        }));
+
+        MockNode {
+            addr,
+            state,
+            shutdown: Some(shutdown)
+        }
+    }
+
+    /// Base url of the node, such as `http://127.0.0.1:34567`
+    pub fn host(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// A client of this node
+    pub fn client(&self) -> IOST {
+        IOST::new(&self.host())
+    }
+
+    /// An in-memory transport answering with the responses of this node
+    pub fn transport(&self) -> MockTransport {
+        MockTransport {
+            state: self.state.clone()
+        }
+    }
+
+    /// A client of this node sending its requests through `transport`
+    pub fn memory_client(&self) -> IOST<MockTransport> {
+        IOST::with_transport(self.transport(), &[&self.host()])
+    }
+
+    /// Answer `path` with `body` and status 200. `path` is either an endpoint such as `getAccount`
+    /// or a full path such as `getAccount/admin/true`, which takes precedence.
+    pub fn respond(&self, path: &str, body: &str) {
+        self.respond_with(path, 200, body);
+    }
+
+    /// Answer `path` with `body` and `status`
+    pub fn respond_with(&self, path: &str, status: u16, body: &str) {
+        let response = MockResponse {
+            status,
+            body: body.to_owned()
+        };
+        self.state.lock().expect("mock state lock; qed").routes.insert(path.to_owned(), response);
+    }
+
+    /// Answer `path` with an `ErrorMessage` body, the way the node reports failures
+    pub fn respond_error(&self, path: &str, status: u16, code: i32, message: &str) {
+        let error = ErrorMessage {
+            code,
+            message: message.to_owned()
+        };
+        let body = serde_json::to_string(&error).expect("ErrorMessage serializes; qed");
+        self.respond_with(path, status, &body);
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().expect("mock state lock; qed").requests.clone()
+    }
+}
+
+impl Drop for MockNode {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+async fn handle(state: Arc<Mutex<State>>, req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
+    let method = req.method().to_string();
+    let path = req.uri().path().trim_start_matches('/').to_owned();
+    let body = hyper::body::to_bytes(req.into_body()).await?;
+    let response = state.lock().expect("mock state lock; qed").handle(method, path, &body);
+
+    Ok(Response::builder()
+        .status(response.status)
+        .header("content-type", "application/json")
+        .body(Body::from(response.body))
+        .expect("status and header are valid; qed"))
+}
+
+/// Transport answering in memory with the responses and request log of a `MockNode`
+#[derive(Clone)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: transport::Request) -> Result<transport::Response, Error> {
+        let method = match request.method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        let path = request.path().to_owned();
+        let body = request.body.unwrap_or_default();
+        let response = self.state.lock().expect("mock state lock; qed").handle(method.to_owned(), path, &body);
+        Ok(transport::Response {
+            status: response.status,
+            body: response.body.into_bytes()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::subscribe::Topic;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn mock_node_should_serve_fixtures() {
+        let node = MockNode::start();
+        let account = node.client().get_account("admin", true).await.unwrap();
+        assert_eq!(account.name, "admin");
+        let requests = node.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "getAccount/admin/true");
+    }
+
+    #[tokio::test]
+    async fn mock_node_should_prefer_full_path() {
+        let node = MockNode::start();
+        node.respond("getCandidateBonus/erebus/true", r#"{"bonus": 1.5}"#);
+        let iost = node.client();
+        assert_eq!(iost.get_candidate_bonus("erebus", true).await.unwrap().bonus, 1.5);
+        assert_eq!(iost.get_candidate_bonus("erebus", false).await.unwrap().bonus, 1234.56789);
+    }
+
+    #[tokio::test]
+    async fn mock_node_should_serve_errors() {
+        let node = MockNode::empty();
+        node.respond_error("getChainInfo", 500, 2, "chain is unavailable");
+        match node.client().get_chain_info().await {
+            Err(Error::ErrorMessage(message)) => {
+                assert_eq!(message.code, 2);
+                assert_eq!(message.message, "chain is unavailable");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match node.client().get_gas_ratio().await {
+            Err(Error::NotFound(message)) => assert_eq!(message.code, 5),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_node_should_record_post_body() {
+        let node = MockNode::start();
+        let post = crate::ContractStorageFieldsPost {
+            id: "token.iost".to_string(),
+            key: "TIiost".to_string(),
+            by_longest_chain: true
+        };
+        node.client().get_contract_storage_fields(post).await.unwrap();
+        let request = &node.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.body, r#"{"id":"token.iost","key":"TIiost","by_longest_chain":true}"#);
+    }
+
+    #[tokio::test]
+    async fn memory_client_should_serve_fixtures() {
+        let node = MockNode::start();
+        node.respond_error("getChainInfo", 400, 2, "chain is unavailable");
+        let iost = node.memory_client();
+        assert_eq!(iost.get_account("admin", true).await.unwrap().name, "admin");
+        assert!(matches!(iost.get_chain_info().await, Err(Error::ErrorMessage(_))));
+        let post = crate::ContractStorageFieldsPost {
+            id: "token.iost".to_string(),
+            key: "TIiost".to_string(),
+            by_longest_chain: true
+        };
+        iost.get_contract_storage_fields(post).await.unwrap();
+        let requests = node.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].path, "getAccount/admin/true");
+        assert_eq!(requests[2].method, "POST");
+        assert_eq!(requests[2].body, r#"{"id":"token.iost","key":"TIiost","by_longest_chain":true}"#);
+    }
+
+    #[tokio::test]
+    async fn memory_client_should_subscribe() {
+        let node = MockNode::start();
+        let events: Vec<_> = node.memory_client()
+            .subscribe(vec![Topic::CONTRACT_EVENT, Topic::CONTRACT_RECEIPT], None)
+            .take(2)
+            .collect()
+            .await;
+        assert_eq!(events[0].as_ref().unwrap().topic, Topic::CONTRACT_EVENT);
+        assert_eq!(events[1].as_ref().unwrap().topic, Topic::CONTRACT_RECEIPT);
+        assert_eq!(node.requests()[0].path, "subscribe");
+    }
+}
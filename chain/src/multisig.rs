@@ -0,0 +1,248 @@
+use crate::error::Error;
+use crate::get_account::Account;
+use crate::item::Item;
+use iost_core::Signature;
+use iost_core::Tx;
+use iost_keys::keypair::Keypair;
+use serde::{Serialize, Deserialize};
+
+/// The permission that can do anything another permission of the account can
+pub const OWNER_PERMISSION: &str = "owner";
+
+/// The permission a signer listed without `@permission` signs with
+pub const ACTIVE_PERMISSION: &str = "active";
+
+/// A transaction collecting the signatures of its `signers` from several parties before the
+/// publisher signs it. It serializes to JSON to be passed around, and copies signed separately
+/// are combined with `merge`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartiallySignedTx {
+    tx: Tx
+}
+
+impl PartiallySignedTx {
+    /// Start collecting signatures for `tx`. Publisher signatures are dropped, since every
+    /// signer signature invalidates them.
+    pub fn new(mut tx: Tx) -> Self {
+        tx.publisher_sigs.clear();
+        PartiallySignedTx { tx }
+    }
+
+    pub fn tx(&self) -> &Tx {
+        &self.tx
+    }
+
+    /// Sign on behalf of one of the `signers`
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), Error> {
+        let signature = Signature::sign(&self.tx.base_hash()?, keypair)?;
+        self.add_signature(signature)
+    }
+
+    /// Add a signature made elsewhere, after verifying it against `Tx::base_hash`.
+    /// A second signature of the same key is ignored.
+    pub fn add_signature(&mut self, signature: Signature) -> Result<(), Error> {
+        signature.verify(&self.tx.base_hash()?)?;
+        if !self.tx.signatures.iter().any(|s| s.public_key == signature.public_key) {
+            self.tx.signatures.push(signature);
+        }
+        Ok(())
+    }
+
+    /// Add the signatures collected by `other`, which must be a copy of the same transaction
+    pub fn merge(&mut self, other: &PartiallySignedTx) -> Result<(), Error> {
+        if self.tx.base_hash()? != other.tx.base_hash()? {
+            return Err(Error::InvalidTx("cannot merge signatures of a different transaction".to_string()));
+        }
+        for signature in &other.tx.signatures {
+            self.add_signature(signature.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Check that the collected signatures meet every permission `account` is listed with in `signers`
+    pub fn check(&self, account: &Account) -> Result<(), Error> {
+        let checker = PermissionChecker::new(account);
+        for signer in &self.tx.signers {
+            let (name, permission) = signer_permission(signer);
+            if name == account.name {
+                checker.check(permission, &self.tx.signatures, &self.tx.signers)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sign as the publisher and return the transaction ready for `IOST::send_tx`
+    pub fn finalize(mut self, publisher_keypair: &Keypair) -> Result<Tx, Error> {
+        self.tx.sign_as_publisher(publisher_keypair)?;
+        Ok(self.tx)
+    }
+}
+
+/// Evaluates signatures against the permissions of a fetched `Account`.
+///
+/// An item that is a key pair counts when one of the signatures is made with that key, an item
+/// naming another account's permission counts when `account@permission` is among the signers.
+pub struct PermissionChecker<'a> {
+    account: &'a Account
+}
+
+impl<'a> PermissionChecker<'a> {
+    pub fn new(account: &'a Account) -> Self {
+        PermissionChecker { account }
+    }
+
+    /// Sum of the weights of the items of `permission` and of its groups that are satisfied
+    pub fn weight(&self, permission: &str, signatures: &[Signature], signers: &[String]) -> Result<i64, Error> {
+        let permission = self.account.permissions.get(permission)
+            .ok_or_else(|| Error::PermissionDenied(format!("{} has no permission {}", self.account.name, permission)))?;
+        let keys = signatures.iter()
+            .map(Signature::public_key_id)
+            .collect::<Result<Vec<_>, _>>()?;
+        let groups = permission.group_names.iter()
+            .filter_map(|name| self.account.groups.get(name))
+            .flat_map(|group| group.items.iter());
+        let weight = permission.items.iter()
+            .chain(groups)
+            .filter(|item| is_satisfied(item, &keys, signers))
+            .map(|item| item.weight)
+            .sum();
+        Ok(weight)
+    }
+
+    /// Whether the weight collected for `permission` meets its threshold,
+    /// or for any permission but `owner`, the threshold of `owner`
+    pub fn is_satisfied(&self, permission: &str, signatures: &[Signature], signers: &[String]) -> Result<bool, Error> {
+        let threshold = self.account.permissions.get(permission).map(|p| p.threshold).unwrap_or(0);
+        if self.weight(permission, signatures, signers)? >= threshold {
+            return Ok(true);
+        }
+        if permission != OWNER_PERMISSION && self.account.permissions.contains_key(OWNER_PERMISSION) {
+            return self.is_satisfied(OWNER_PERMISSION, signatures, signers);
+        }
+        Ok(false)
+    }
+
+    /// Fail with `PermissionDenied` unless `is_satisfied`
+    pub fn check(&self, permission: &str, signatures: &[Signature], signers: &[String]) -> Result<(), Error> {
+        if self.is_satisfied(permission, signatures, signers)? {
+            Ok(())
+        } else {
+            let threshold = self.account.permissions.get(permission).map(|p| p.threshold).unwrap_or(0);
+            let weight = self.weight(permission, signatures, signers)?;
+            Err(Error::PermissionDenied(format!("{}@{} has weight {} of threshold {}", self.account.name, permission, weight, threshold)))
+        }
+    }
+}
+
+fn is_satisfied(item: &Item, keys: &[String], signers: &[String]) -> bool {
+    if item.is_key_pair {
+        keys.contains(&item.id)
+    } else {
+        signers.iter().any(|signer| signer_permission(signer) == (item.id.as_str(), item.permission.as_str()))
+    }
+}
+
+/// Split `name@permission`, a bare `name` signing with `ACTIVE_PERMISSION`
+fn signer_permission(signer: &str) -> (&str, &str) {
+    let mut parts = signer.splitn(2, '@');
+    let name = parts.next().unwrap_or_default();
+    (name, parts.next().unwrap_or(ACTIVE_PERMISSION))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tx_builder::TxBuilder;
+    use iost_keys::algorithm::KeyAlgorithm;
+    use serde_json::json;
+
+    fn keypair(seed: u8) -> Keypair {
+        Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[seed; 32]).unwrap()
+    }
+
+    fn key_item(seed: u8, weight: i64) -> serde_json::Value {
+        json!({"id": keypair(seed).public_key_iost_string(), "is_key_pair": true, "weight": weight.to_string(), "permission": ""})
+    }
+
+    /// `multisig` with an `active` permission of threshold 2 over keys 1, 2 and a group holding key 3,
+    /// and an `owner` permission held by key 9 alone
+    fn account() -> Account {
+        let mut account: serde_json::Value = serde_json::from_str(include_str!("../fixtures/getAccount.json")).unwrap();
+        account["name"] = json!("multisig");
+        account["permissions"] = json!({
+            "active": {"name": "active", "group_names": ["devs"], "items": [key_item(1, 1), key_item(2, 1)], "threshold": "2"},
+            "owner": {"name": "owner", "group_names": [], "items": [key_item(9, 1)], "threshold": "1"},
+            "transfer": {"name": "transfer", "group_names": [], "items": [
+                {"id": "treasurer", "is_key_pair": false, "weight": "1", "permission": "active"}
+            ], "threshold": "1"}
+        });
+        account["groups"] = json!({"devs": {"name": "devs", "items": [key_item(3, 1)]}});
+        serde_json::from_value(account).unwrap()
+    }
+
+    fn partial_tx() -> PartiallySignedTx {
+        let tx = TxBuilder::new("admin").time(1_000_000_000).signer("multisig@active").build().unwrap();
+        PartiallySignedTx::new(tx)
+    }
+
+    #[test]
+    fn permission_checker_should_sum_weights() {
+        let account = account();
+        let checker = PermissionChecker::new(&account);
+        let hash = [0u8; 32];
+        let sign = |seed| Signature::sign(&hash, &keypair(seed)).unwrap();
+
+        assert_eq!(checker.weight("active", &[sign(1)], &[]).unwrap(), 1);
+        assert!(!checker.is_satisfied("active", &[sign(1)], &[]).unwrap());
+        assert!(checker.is_satisfied("active", &[sign(1), sign(3)], &[]).unwrap());
+        assert!(checker.is_satisfied("active", &[sign(9)], &[]).unwrap());
+        assert!(!checker.is_satisfied("owner", &[sign(1), sign(2)], &[]).unwrap());
+        assert!(checker.is_satisfied("transfer", &[], &["treasurer@active".to_string()]).unwrap());
+        assert!(matches!(checker.check("active", &[sign(2)], &[]), Err(Error::PermissionDenied(_))));
+        assert!(matches!(checker.weight("missing", &[], &[]), Err(Error::PermissionDenied(_))));
+    }
+
+    #[test]
+    fn partially_signed_tx_should_merge_and_finalize() {
+        let account = account();
+        let mut first = partial_tx();
+        first.sign(&keypair(1)).unwrap();
+        assert!(matches!(first.check(&account), Err(Error::PermissionDenied(_))));
+
+        let json = serde_json::to_string(&partial_tx()).unwrap();
+        let mut second: PartiallySignedTx = serde_json::from_str(&json).unwrap();
+        second.sign(&keypair(2)).unwrap();
+        second.sign(&keypair(2)).unwrap();
+
+        first.merge(&second).unwrap();
+        assert_eq!(first.tx().signatures.len(), 2);
+        assert!(first.check(&account).is_ok());
+
+        let tx = first.finalize(&keypair(7)).unwrap();
+        assert!(tx.publisher_sigs[0].verify(&tx.publish_hash().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn partially_signed_tx_should_check_bare_signers_as_active() {
+        let account = account();
+        let tx = TxBuilder::new("admin").time(1_000_000_000).signer("multisig").build().unwrap();
+        let mut partial = PartiallySignedTx::new(tx);
+        partial.sign(&keypair(1)).unwrap();
+        assert!(matches!(partial.check(&account), Err(Error::PermissionDenied(_))));
+        partial.sign(&keypair(2)).unwrap();
+        assert!(partial.check(&account).is_ok());
+
+        let checker = PermissionChecker::new(&account);
+        assert!(checker.is_satisfied("transfer", &[], &["treasurer".to_string()]).unwrap());
+    }
+
+    #[test]
+    fn partially_signed_tx_should_reject_foreign_signatures() {
+        let mut partial = partial_tx();
+        let mut other = PartiallySignedTx::new(TxBuilder::new("admin").time(2_000_000_000).build().unwrap());
+        other.sign(&keypair(1)).unwrap();
+        assert!(partial.merge(&other).is_err());
+        assert!(partial.add_signature(other.tx().signatures[0].clone()).is_err());
+        assert!(partial.tx().signatures.is_empty());
+    }
+}
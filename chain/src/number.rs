@@ -0,0 +1,272 @@
+//! Numbers of the IOST API, which encodes 64-bit integers and decimals as JSON strings
+
+use crate::error::Error;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serde adapter for integers the node sends as strings, such as `"54283450"`.
+/// Plain JSON numbers are accepted as well; values are serialized back as strings.
+///
+/// Use it with `#[serde(with = "crate::number::string")]`.
+pub mod string {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where T: fmt::Display,
+              S: Serializer
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where T: FromStr,
+              T::Err: fmt::Display,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_any(NumberVisitor(core::marker::PhantomData))
+    }
+}
+
+struct NumberVisitor<T>(core::marker::PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for NumberVisitor<T>
+    where T: FromStr,
+          T::Err: fmt::Display
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a string containing a number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.trim().parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    /// With the `arbitrary_precision` feature of serde_json, which this crate enables, a JSON
+    /// number arrives as its raw token rather than through `f64`
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        let number = serde_json::Number::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        self.visit_str(&number.to_string())
+    }
+}
+
+/// An exact decimal number, such as vote counts, that would lose precision as `f64`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// `mantissa * 10^-scale`
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        let mut decimal = Decimal { mantissa, scale };
+        while decimal.scale > 0 && decimal.mantissa % 10 == 0 {
+            decimal.mantissa /= 10;
+            decimal.scale -= 1;
+        }
+        decimal
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of digits after the decimal point
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// The mantissa at a larger `scale`, or `None` when `scale` is smaller or on overflow
+    pub(crate) fn rescale(&self, scale: u32) -> Option<i128> {
+        10i128.checked_pow(scale.checked_sub(self.scale)?)?.checked_mul(self.mantissa)
+    }
+
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        Some(Decimal::new(self.rescale(scale)?.checked_add(other.rescale(scale)?)?, scale))
+    }
+
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        Some(Decimal::new(self.rescale(scale)?.checked_sub(other.rescale(scale)?)?, scale))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(self.scale as i32)
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => self.to_f64().partial_cmp(&other.to_f64()).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(v: i64) -> Self {
+        Decimal::new(i128::from(v), 0)
+    }
+}
+
+/// Parse a decimal such as `"-1.5"`, with an optional exponent such as `"5e-07"` as Go's
+/// `encoding/json` writes small `float64` values
+impl FromStr for Decimal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidNumber(s.to_owned());
+        let (number, exponent) = match s.find(['e', 'E']) {
+            Some(e) => (&s[..e], s[e + 1..].parse::<i32>().map_err(|_| invalid())?),
+            None => (s, 0),
+        };
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (int, frac) = match digits.find('.') {
+            Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+            None => (digits, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return Err(invalid());
+        }
+        let mut mantissa: i128 = 0;
+        for c in int.chars().chain(frac.chars()) {
+            let digit = c.to_digit(10).ok_or_else(invalid)?;
+            mantissa = mantissa.checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(digit)))
+                .ok_or_else(invalid)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        let scale = i64::try_from(frac.len()).map_err(|_| invalid())? - i64::from(exponent);
+        if scale < 0 {
+            let factor = u32::try_from(-scale).ok().and_then(|e| 10i128.checked_pow(e));
+            mantissa = factor.and_then(|f| mantissa.checked_mul(f)).ok_or_else(invalid)?;
+        }
+        Ok(Decimal::new(mantissa, u32::try_from(scale.max(0)).map_err(|_| invalid())?))
+    }
+}
+
+/// Formats with the digits of the number, or at least as many digits after the decimal point as
+/// a precision asks for, e.g. `format!("{:.8}", decimal)` for `"1.50000000"`
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let padding = f.precision().unwrap_or(0).saturating_sub(self.scale as usize);
+        let digits = format!("{}{}", self.mantissa.unsigned_abs(), "0".repeat(padding));
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let scale = self.scale as usize + padding;
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, int, frac)
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NumberVisitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Numbers {
+        #[serde(with = "crate::number::string")]
+        number: u64,
+        #[serde(with = "crate::number::string")]
+        time: i64,
+        votes: Decimal
+    }
+
+    #[test]
+    fn string_numbers_should_deserialize() {
+        let numbers: Numbers = serde_json::from_str(r#"{"number": "54283450", "time": 1565253394001553000, "votes": "2100000.5"}"#).unwrap();
+        assert_eq!(numbers.number, 54283450);
+        assert_eq!(numbers.time, 1565253394001553000);
+        assert_eq!(numbers.votes, Decimal::new(21000005, 1));
+        assert_eq!(serde_json::to_string(&numbers).unwrap(), r#"{"number":"54283450","time":"1565253394001553000","votes":"2100000.5"}"#);
+        assert!(serde_json::from_str::<Numbers>(r#"{"number": "-1", "time": 0, "votes": 0}"#).is_err());
+    }
+
+    #[test]
+    fn json_numbers_should_keep_all_digits() {
+        let numbers: Numbers = serde_json::from_str(r#"{"number": 18446744073709551615, "time": -1, "votes": 982678652.12345678}"#).unwrap();
+        assert_eq!(numbers.number, u64::MAX);
+        assert_eq!(numbers.votes, Decimal::new(98267865212345678, 8));
+        let votes: Decimal = serde_json::from_value(serde_json::json!(0.1)).unwrap();
+        assert_eq!(votes, Decimal::new(1, 1));
+        let dust: Decimal = serde_json::from_str("5e-07").unwrap();
+        assert_eq!(dust, Decimal::new(5, 7));
+    }
+
+    #[test]
+    fn decimal_should_parse_and_format() {
+        assert_eq!("2100000".parse::<Decimal>().unwrap().to_string(), "2100000");
+        assert_eq!("0.00010".parse::<Decimal>().unwrap().to_string(), "0.0001");
+        assert_eq!("-1.5".parse::<Decimal>().unwrap().to_string(), "-1.5");
+        assert_eq!(".5".parse::<Decimal>().unwrap(), Decimal::new(5, 1));
+        assert_eq!(format!("{:.8}", "1.5".parse::<Decimal>().unwrap()), "1.50000000");
+        assert_eq!(format!("{:.2}", Decimal::from(-3)), "-3.00");
+        assert_eq!(format!("{:.1}", Decimal::new(12345, 3)), "12.345");
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("".parse::<Decimal>().is_err());
+        assert_eq!("5e-07".parse::<Decimal>().unwrap(), Decimal::new(5, 7));
+        assert_eq!("1.25E+2".parse::<Decimal>().unwrap(), Decimal::from(125));
+        assert_eq!("-2.5e-10".parse::<Decimal>().unwrap().to_string(), "-0.00000000025");
+        assert!("1e".parse::<Decimal>().is_err());
+        assert!("e5".parse::<Decimal>().is_err());
+        assert!("1e99".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn decimal_should_do_arithmetic() {
+        let a: Decimal = "2100000.25".parse().unwrap();
+        let b: Decimal = "0.75".parse().unwrap();
+        assert_eq!(a.checked_add(&b).unwrap(), Decimal::from(2100001));
+        assert_eq!(b.checked_sub(&a).unwrap().to_string(), "-2099999.5");
+        assert!(a > b);
+        assert_eq!(b.to_f64(), 0.75);
+        assert!(Decimal::new(i128::MAX, 0).checked_add(&Decimal::from(1)).is_none());
+    }
+}
@@ -10,5 +10,6 @@ pub struct Permission {
     /// permission information
     pub items: Vec<Item>,
     /// permission threshold
-    pub threshold: String
+    #[serde(with = "crate::number::string")]
+    pub threshold: i64
 }
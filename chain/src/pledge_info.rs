@@ -1,3 +1,4 @@
+use crate::number::Decimal;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -5,5 +6,5 @@ pub struct PledgeInfo {
     /// the account receiving the deposit
     pub pledger: String,
     /// 	the amount of the deposit
-    pub amount: f64
+    pub amount: Decimal
 }
@@ -3,9 +3,12 @@ use serde::{Serialize, Deserialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RAMInfo {
     /// RAM bytes available for use
-    pub available: String,
+    #[serde(with = "crate::number::string")]
+    pub available: i64,
     /// RAM bytes used
-    pub used: String,
+    #[serde(with = "crate::number::string")]
+    pub used: i64,
     /// RAM bytes total
-    pub total: String
+    #[serde(with = "crate::number::string")]
+    pub total: i64
 }
@@ -0,0 +1,142 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// How `IOST` retries a request that failed on the transport or with a 5xx response
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// attempts after the first one; 0 disables retries
+    pub max_retries: u32,
+    /// wait before the first retry
+    pub initial_backoff: Duration,
+    /// upper bound of the wait between two attempts
+    pub max_backoff: Duration,
+    /// factor the wait grows by after every retry
+    pub multiplier: f64
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A single attempt per request
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Wait before retry number `retry`, counting from 1, between zero and `max_backoff` even
+    /// for a negative or NaN `multiplier`
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.powi(retry.saturating_sub(1) as i32);
+        let backoff = self.initial_backoff.as_secs_f64() * factor;
+        Duration::from_secs_f64(backoff.max(0.0).min(self.max_backoff.as_secs_f64()))
+    }
+}
+
+/// Health of one endpoint of an `IOST` client
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointHealth {
+    /// base url of the node
+    pub host: String,
+    /// failed attempts since the last successful one
+    pub consecutive_failures: usize
+}
+
+/// Endpoints of a client with the consecutive failures of each
+pub(crate) struct Endpoints {
+    hosts: Vec<String>,
+    failures: Vec<AtomicUsize>,
+}
+
+impl Endpoints {
+    pub(crate) fn new(hosts: Vec<String>) -> Self {
+        let failures = hosts.iter().map(|_| AtomicUsize::new(0)).collect();
+        Endpoints { hosts, failures }
+    }
+
+    /// Indices of the endpoints in the order a request tries them: healthier endpoints first,
+    /// ties in configured order
+    pub(crate) fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.hosts.len()).collect();
+        order.sort_by_key(|i| self.failures[*i].load(Ordering::Relaxed));
+        order
+    }
+
+    pub(crate) fn host(&self, index: usize) -> &str {
+        &self.hosts[index]
+    }
+
+    pub(crate) fn succeeded(&self, index: usize) {
+        self.failures[index].store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn failed(&self, index: usize) {
+        self.failures[index].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn health(&self) -> Vec<EndpointHealth> {
+        self.hosts.iter().zip(&self.failures)
+            .map(|(host, failures)| EndpointHealth {
+                host: host.clone(),
+                consecutive_failures: failures.load(Ordering::Relaxed)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn backoff_should_grow_until_max() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            multiplier: 2.0
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(RetryPolicy::none().max_retries, 0);
+    }
+
+    #[test]
+    fn backoff_should_not_go_negative() {
+        let policy = RetryPolicy {
+            multiplier: -2.0,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(2), Duration::from_secs(0));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        let policy = RetryPolicy {
+            multiplier: f64::NAN,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(2), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn endpoints_should_prefer_healthy() {
+        let endpoints = Endpoints::new(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(endpoints.order(), vec![0, 1, 2]);
+        endpoints.failed(0);
+        assert_eq!(endpoints.order(), vec![1, 2, 0]);
+        endpoints.succeeded(0);
+        assert_eq!(endpoints.order(), vec![0, 1, 2]);
+        endpoints.failed(1);
+        assert_eq!(endpoints.health()[1], EndpointHealth { host: "b".to_string(), consecutive_failures: 1 });
+    }
+}
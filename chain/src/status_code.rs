@@ -1,7 +1,7 @@
 use serde::{Serialize, Deserialize};
 
 #[allow(non_camel_case_types)]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub enum StatusCode {
     SUCCESS,
     GAS_RUN_OUT,
@@ -0,0 +1,209 @@
+use crate::error::Error;
+use crate::get_batch_contract_storage::BatchContractStoragePost;
+use crate::get_contract_storage::ContractStoragePost;
+use crate::get_contract_storage_fields::ContractStorageFieldsPost;
+use crate::key_field::KeyField;
+use crate::transport::{HttpTransport, Transport};
+use crate::IOST;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A value read from contract storage and the block it was read at
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stored<T> {
+    /// the decoded value
+    pub value: T,
+    /// the hash of block from which the data is from
+    pub block_hash: String,
+    /// the number of block from which the data is from
+    pub block_number: u64
+}
+
+/// Typed reads of contract storage. Stored data is decoded from JSON, or taken as a plain
+/// string when it is not JSON; an empty or `null` entry reads as `None`.
+///
+/// A pinned reader remembers the block of its first read and fails later reads that come from
+/// another block with `BlockChanged`, so a set of reads is known to be consistent.
+pub struct StorageReader<'a, T = HttpTransport> {
+    iost: &'a IOST<T>,
+    by_longest_chain: bool,
+    pinned: Option<Mutex<Option<(String, u64)>>>,
+}
+
+impl<'a, T: Transport> StorageReader<'a, T> {
+    /// A reader of the longest chain
+    pub fn new(iost: &'a IOST<T>) -> Self {
+        StorageReader {
+            iost,
+            by_longest_chain: true,
+            pinned: None
+        }
+    }
+
+    /// Read from irreversible blocks instead of the longest chain
+    pub fn irreversible(mut self) -> Self {
+        self.by_longest_chain = false;
+        self
+    }
+
+    /// Require all reads to come from the block of the first one
+    pub fn pinned(mut self) -> Self {
+        self.pinned = Some(Mutex::new(None));
+        self
+    }
+
+    /// The block the reader is pinned to, once it has read
+    pub fn pinned_block(&self) -> Option<(String, u64)> {
+        self.pinned.as_ref().and_then(|pinned| pinned.lock().unwrap().clone())
+    }
+
+    /// Read `key`, or `field` of the map at `key`, of `contract`. `field` is empty for plain keys
+    pub async fn get<V: DeserializeOwned>(&self, contract: &str, key: &str, field: &str) -> Result<Option<V>, Error> {
+        Ok(self.get_stored(contract, key, field).await?.value)
+    }
+
+    /// `get` together with the block the value was read at
+    pub async fn get_stored<V: DeserializeOwned>(&self, contract: &str, key: &str, field: &str) -> Result<Stored<Option<V>>, Error> {
+        let storage = self.iost.get_contract_storage(ContractStoragePost {
+            id: contract.to_owned(),
+            key: key.to_owned(),
+            field: field.to_owned(),
+            by_longest_chain: self.by_longest_chain
+        }).await?;
+        self.pin(&storage.block_hash, storage.block_number)?;
+        Ok(Stored {
+            value: decode(&storage.data)?,
+            block_hash: storage.block_hash,
+            block_number: storage.block_number
+        })
+    }
+
+    /// Read several entries of `contract` at the same block, mapping each key field to its value
+    pub async fn batch_get<V: DeserializeOwned>(&self, contract: &str, key_fields: Vec<KeyField>) -> Result<Stored<HashMap<KeyField, Option<V>>>, Error> {
+        let storage = self.iost.get_batch_contract_storage(BatchContractStoragePost {
+            id: contract.to_owned(),
+            key_fields: key_fields.clone(),
+            by_longest_chain: self.by_longest_chain
+        }).await?;
+        if storage.datas.len() != key_fields.len() {
+            return Err(Error::UnexpectedResponse(format!("{} values for {} keys", storage.datas.len(), key_fields.len())));
+        }
+        self.pin(&storage.block_hash, storage.block_number)?;
+        let value = key_fields.into_iter()
+            .zip(&storage.datas)
+            .map(|(key_field, data)| Ok((key_field, decode(data)?)))
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+        Ok(Stored {
+            value,
+            block_hash: storage.block_hash,
+            block_number: storage.block_number
+        })
+    }
+
+    /// The fields of the map at `key` of `contract`
+    pub async fn fields(&self, contract: &str, key: &str) -> Result<Vec<String>, Error> {
+        let fields = self.iost.get_contract_storage_fields(ContractStorageFieldsPost {
+            id: contract.to_owned(),
+            key: key.to_owned(),
+            by_longest_chain: self.by_longest_chain
+        }).await?;
+        Ok(fields.fields)
+    }
+
+    fn pin(&self, block_hash: &str, block_number: u64) -> Result<(), Error> {
+        let pinned = match &self.pinned {
+            Some(pinned) => pinned,
+            None => return Ok(()),
+        };
+        let mut pinned = pinned.lock().unwrap();
+        match &*pinned {
+            Some((hash, number)) if hash != block_hash => Err(Error::BlockChanged(format!(
+                "read block {} ({}) while pinned to block {} ({})", block_number, block_hash, number, hash))),
+            Some(_) => Ok(()),
+            None => {
+                *pinned = Some((block_hash.to_owned(), block_number));
+                Ok(())
+            }
+        }
+    }
+}
+
+fn decode<T: DeserializeOwned>(data: &str) -> Result<Option<T>, Error> {
+    if data.is_empty() || data == "null" {
+        return Ok(None);
+    }
+    match serde_json::from_str(data) {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => serde_json::from_value(Value::String(data.to_owned()))
+            .map(Some)
+            .map_err(|_| Error::Json(e)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockNode;
+
+    fn key_field(key: &str, field: &str) -> KeyField {
+        KeyField {
+            key: key.to_string(),
+            field: field.to_string()
+        }
+    }
+
+    #[test]
+    fn decode_should_be_ok() {
+        assert_eq!(decode::<u64>("8").unwrap(), Some(8));
+        assert_eq!(decode::<String>("admin").unwrap(), Some("admin".to_string()));
+        assert_eq!(decode::<String>(r#""admin""#).unwrap(), Some("admin".to_string()));
+        assert_eq!(decode::<Vec<i32>>("[1,2]").unwrap(), Some(vec![1, 2]));
+        assert_eq!(decode::<u64>("null").unwrap(), None);
+        assert_eq!(decode::<u64>("").unwrap(), None);
+        assert!(decode::<u64>("admin").is_err());
+    }
+
+    #[tokio::test]
+    async fn get_should_decode_storage() {
+        let node = MockNode::start();
+        let iost = node.client();
+        let reader = StorageReader::new(&iost);
+        assert_eq!(reader.get::<u32>("token.iost", "TIiost", "decimal").await.unwrap(), Some(8));
+        let stored = reader.get_stored::<u32>("token.iost", "TIiost", "decimal").await.unwrap();
+        assert_eq!(stored.block_number, 54283450);
+        assert_eq!(reader.fields("token.iost", "TIiost").await.unwrap()[0], "decimal");
+        assert!(node.requests()[0].body.contains(r#""by_longest_chain":true"#));
+
+        let reader = StorageReader::new(&iost).irreversible();
+        reader.get::<u32>("token.iost", "TIiost", "decimal").await.unwrap();
+        assert!(node.requests().last().unwrap().body.contains(r#""by_longest_chain":false"#));
+    }
+
+    #[tokio::test]
+    async fn batch_get_should_map_keys_to_values() {
+        let node = MockNode::start();
+        let iost = node.client();
+        let reader = StorageReader::new(&iost);
+        let stored = reader.batch_get::<u64>("token.iost", vec![key_field("supply", "TIiost"), key_field("decimal", "TIiost")]).await.unwrap();
+        assert_eq!(stored.value[&key_field("supply", "TIiost")], Some(2135480271733812600));
+        assert_eq!(stored.value[&key_field("decimal", "TIiost")], Some(8));
+        assert!(reader.batch_get::<u64>("token.iost", vec![key_field("decimal", "TIiost")]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn pinned_reads_should_stay_on_one_block() {
+        let node = MockNode::start();
+        let iost = node.client();
+        let reader = StorageReader::new(&iost).pinned();
+        assert!(reader.pinned_block().is_none());
+        reader.get::<u32>("token.iost", "TIiost", "decimal").await.unwrap();
+        reader.get::<u32>("token.iost", "TIiost", "decimal").await.unwrap();
+        assert_eq!(reader.pinned_block(), Some(("GexerugLra5qBArG4vqCAFNX1F7WzLzpPdmzcjLBAi3k".to_string(), 54283450)));
+
+        node.respond("getContractStorage", r#"{"data": "8", "block_hash": "6q8hTpW4dYt7bXkH7LyoyEdkHUtA1tuPF4XbvnPpHUsL", "block_number": "54283451"}"#);
+        let result = reader.get::<u32>("token.iost", "TIiost", "decimal").await;
+        assert!(matches!(result, Err(Error::BlockChanged(_))));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::error::Error;
+use crate::message::ErrorMessage;
+use crate::transport::{BodyStream, Request, Transport};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::{Serialize, Deserialize};
+use std::time::Duration;
+
+/// Time to wait before reconnecting once the subscription stream dropped
+pub const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Topic {
+    /// events emitted by contracts
+    CONTRACT_EVENT,
+    /// receipts of contract calls
+    CONTRACT_RECEIPT
+}
+
+#[derive(Clone, Serialize, Debug)]
+pub struct SubscribeFilter {
+    /// only subscribe to the events of this contract
+    pub contract_id: String
+}
+
+#[derive(Clone, Serialize, Debug)]
+pub struct SubscribePost {
+    /// topics to subscribe to
+    pub topics: Vec<Topic>,
+    /// filter of the events, all events of the topics when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SubscribeFilter>
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Event {
+    /// topic of the event
+    pub topic: Topic,
+    /// content of the event, usually JSON
+    pub data: String,
+    /// time of the event. Unixepoch start in nanoseconds
+    #[serde(with = "crate::number::string")]
+    pub time: i64
+}
+
+#[derive(Deserialize, Debug)]
+struct SubscribeResult {
+    event: Event
+}
+
+/// One line of the chunked `subscribe` response
+#[derive(Deserialize, Debug)]
+struct SubscribeResponse {
+    result: Option<SubscribeResult>,
+    error: Option<ErrorMessage>
+}
+
+struct Subscription<T> {
+    transport: T,
+    url: String,
+    param: SubscribePost,
+    reconnect_delay: Duration,
+    response: Option<BodyStream>,
+    connected: bool,
+    buffer: Vec<u8>,
+}
+
+impl<T: Transport> Subscription<T> {
+    async fn connect(&mut self) -> Result<(), Error> {
+        if self.connected {
+            tokio::time::delay_for(self.reconnect_delay).await;
+        }
+        self.connected = true;
+        self.buffer.clear();
+        let param = serde_json::to_vec(&self.param).map_err(Error::Json)?;
+        let response = self.transport.send_streaming(Request::post(self.url.clone(), param)).await?;
+        if response.status == 200 {
+            self.response = Some(response.body);
+            Ok(())
+        } else {
+            let body = response.body.try_concat().await?;
+            let rsp = serde_json::from_slice::<ErrorMessage>(&body).map_err(Error::Json)?;
+            Err(Error::from(rsp))
+        }
+    }
+
+    fn take_line(&mut self) -> Option<Vec<u8>> {
+        let end = self.buffer.iter().position(|b| *b == b'\n')?;
+        let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+        line.pop();
+        Some(line)
+    }
+
+    async fn next_event(&mut self) -> Result<Event, Error> {
+        loop {
+            if let Some(line) = self.take_line() {
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                return decode_event(&line);
+            }
+            let response = match self.response.as_mut() {
+                Some(response) => response,
+                None => {
+                    self.connect().await?;
+                    continue;
+                }
+            };
+            match response.next().await {
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                None => {
+                    self.response = None;
+                    // the last event before the stream closed may lack its newline
+                    if !self.buffer.is_empty() {
+                        self.buffer.push(b'\n');
+                    }
+                }
+                Some(Err(e)) => {
+                    self.response = None;
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
+fn decode_event(line: &[u8]) -> Result<Event, Error> {
+    let response: SubscribeResponse = serde_json::from_slice(line).map_err(Error::Json)?;
+    match (response.result, response.error) {
+        (Some(result), _) => Ok(result.event),
+        (None, Some(error)) => Err(Error::from(error)),
+        (None, None) => Err(Error::ErrorMessage(ErrorMessage {
+            code: 0,
+            message: "subscribe response without result".to_owned()
+        })),
+    }
+}
+
+/// Endless stream of the events posted to `url`, reconnecting whenever the stream drops.
+/// Errors are yielded without ending the stream; drop it to unsubscribe.
+pub(crate) fn subscribe<T: Transport + 'static>(
+    transport: T,
+    url: String,
+    param: SubscribePost,
+    reconnect_delay: Duration
+) -> impl Stream<Item = Result<Event, Error>> {
+    let subscription = Subscription {
+        transport,
+        url,
+        param,
+        reconnect_delay,
+        response: None,
+        connected: false,
+        buffer: Vec::new(),
+    };
+    stream::unfold(subscription, |mut subscription| async move {
+        let event = subscription.next_event().await;
+        Some((event, subscription))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transport::HttpTransport;
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Response, Server};
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const EVENT: &str = r#"{"result":{"event":{"topic":"CONTRACT_EVENT","data":"[\"transfer\"]","time":"1565000000000000000"}}}"#;
+    const RECEIPT: &str = r#"{"result":{"event":{"topic":"CONTRACT_RECEIPT","data":"{\"func_name\":\"token.iost/transfer\"}","time":"1565000000000000001"}}}"#;
+
+    /// Serves two events split across chunks on the first connection and one without a trailing
+    /// newline on the next ones
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        let make_svc = make_service_fn(move |_| {
+            let counter = counter.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_req| {
+                    let n = counter.fetch_add(1, Ordering::SeqCst);
+                    let chunks: Vec<Result<String, Infallible>> = if n == 0 {
+                        let (head, tail) = EVENT.split_at(20);
+                        vec![Ok(head.to_string()), Ok(format!("{}\n\n", tail)), Ok(format!("{}\n", RECEIPT))]
+                    } else {
+                        vec![Ok(RECEIPT.to_string())]
+                    };
+                    async move { Ok::<_, Infallible>(Response::new(Body::wrap_stream(stream::iter(chunks)))) }
+                }))
+            }
+        });
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+        let url = format!("http://{}/subscribe", server.local_addr());
+        tokio::spawn(server);
+        (url, connections)
+    }
+
+    #[test]
+    fn decode_event_should_be_ok() {
+        let event = decode_event(EVENT.as_bytes()).unwrap();
+        assert_eq!(event.topic, Topic::CONTRACT_EVENT);
+        assert_eq!(event.data, r#"["transfer"]"#);
+        assert_eq!(event.time, 1565000000000000000);
+        let error = decode_event(br#"{"error":{"code":2,"message":"bad topic"}}"#);
+        assert!(error.is_err());
+        assert!(decode_event(b"not json").is_err());
+    }
+
+    #[test]
+    fn subscribe_post_should_serialize() {
+        let post = SubscribePost {
+            topics: vec![Topic::CONTRACT_EVENT],
+            filter: Some(SubscribeFilter { contract_id: "token.iost".to_string() })
+        };
+        assert_eq!(serde_json::to_string(&post).unwrap(), r#"{"topics":["CONTRACT_EVENT"],"filter":{"contract_id":"token.iost"}}"#);
+    }
+
+    #[tokio::test]
+    async fn subscribe_should_reconnect() {
+        let (url, connections) = serve();
+        let post = SubscribePost { topics: vec![Topic::CONTRACT_EVENT, Topic::CONTRACT_RECEIPT], filter: None };
+        let events: Vec<_> = subscribe(HttpTransport::new(), url, post, Duration::from_millis(10))
+            .take(4)
+            .collect()
+            .await;
+        let events: Vec<Event> = events.into_iter().map(Result::unwrap).collect();
+        assert_eq!(events[0].topic, Topic::CONTRACT_EVENT);
+        assert_eq!(events[1].topic, Topic::CONTRACT_RECEIPT);
+        assert_eq!(events[2], events[1]);
+        assert_eq!(events[3], events[1]);
+        assert_eq!(connections.load(Ordering::SeqCst), 3);
+    }
+}
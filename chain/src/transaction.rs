@@ -1,5 +1,5 @@
-use crate::action::Action;
-use crate::amount_limit::AmountLimit;
+use iost_core::Action;
+use iost_core::AmountLimit;
 use crate::tx_receipt::TxReceipt;
 use serde::{Serialize, Deserialize};
 
@@ -8,17 +8,20 @@ pub struct Transaction {
     /// transaction's hash
     pub hash: String,
     /// timestamp of the transaction
-    pub time: String,
+    #[serde(with = "crate::number::string")]
+    pub time: i64,
     /// the expiration of the transaction
-    pub expiration: String,
+    #[serde(with = "crate::number::string")]
+    pub expiration: i64,
     /// GAS ratio, we recommend it to be 1.00 (1.00 – 100.00). Raise the ratio to let the network pack it faster
-    pub gas_ratio: i32,
+    pub gas_ratio: f64,
     /// Upper limits of GAS. This transaction will never cost more GAS than this amount
-    pub gas_limit: i32,
+    pub gas_limit: f64,
     /// Transactions will be delayed by this much, in nanosecond
-    pub delay: String,
+    #[serde(with = "crate::number::string")]
+    pub delay: i64,
     /// id of blockchain on which the transaction could be executed
-    pub chain_id: i32,
+    pub chain_id: u32,
     /// the smallest transaction execution unit
     pub actions: Vec<Action>,
     /// list of transaction signatures
@@ -0,0 +1,182 @@
+//! Raw request/response layer under the `IOST` client.
+//!
+//! `IOST` builds the url and JSON body of every endpoint, retries and fails over between nodes,
+//! and decodes the responses; a `Transport` only carries the bytes. `HttpTransport` does so over
+//! `reqwest`; other transports can use another HTTP client, answer in memory or replay recordings.
+
+use crate::error::Error;
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+
+/// HTTP method of a request
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    /// HTTP method
+    pub method: Method,
+    /// full url, such as `http://127.0.0.1:30001/getAccount/admin/true`
+    pub url: String,
+    /// JSON body of a `Post` request
+    pub body: Option<Vec<u8>>
+}
+
+impl Request {
+    pub fn get(url: String) -> Self {
+        Request {
+            method: Method::Get,
+            url,
+            body: None
+        }
+    }
+
+    pub fn post(url: String, body: Vec<u8>) -> Self {
+        Request {
+            method: Method::Post,
+            url,
+            body: Some(body)
+        }
+    }
+
+    /// The url without scheme and host, such as `getAccount/admin/true`
+    pub fn path(&self) -> &str {
+        let url = match self.url.find("://") {
+            Some(start) => &self.url[start + 3..],
+            None => &self.url,
+        };
+        match url.find('/') {
+            Some(start) => &url[start + 1..],
+            None => "",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// response body, usually JSON
+    pub body: Vec<u8>
+}
+
+/// Chunks of a response body in the order they arrive
+pub type BodyStream = BoxStream<'static, Result<Vec<u8>, Error>>;
+
+pub struct StreamingResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// response body, read as it arrives
+    pub body: BodyStream
+}
+
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Send `request` and read the whole response. Errors are failures to reach the node or to
+    /// read its answer; a response with any status is `Ok`.
+    async fn send(&self, request: Request) -> Result<Response, Error>;
+
+    /// Send `request` and read the response body as it arrives, as `subscribe` does. By default
+    /// the whole body is read by `send` and streamed as one chunk.
+    async fn send_streaming(&self, request: Request) -> Result<StreamingResponse, Error> {
+        let response = self.send(request).await?;
+        Ok(StreamingResponse {
+            status: response.status,
+            body: stream::once(async move { Ok(response.body) }).boxed()
+        })
+    }
+}
+
+/// Transport over HTTP with `reqwest`
+#[derive(Clone, Default)]
+pub struct HttpTransport {
+    client: reqwest::Client,
+}
+
+impl HttpTransport {
+    pub fn new() -> Self {
+        HttpTransport::default()
+    }
+
+    /// A transport sending with `client`, e.g. one with a proxy, timeouts or custom TLS
+    pub fn with_client(client: reqwest::Client) -> Self {
+        HttpTransport { client }
+    }
+
+    fn request(&self, request: Request) -> reqwest::RequestBuilder {
+        match request.method {
+            Method::Get => self.client.get(&request.url),
+            Method::Post => self.client
+                .post(&request.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(request.body.unwrap_or_default()),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for HttpTransport {
+    async fn send(&self, request: Request) -> Result<Response, Error> {
+        let response = self.request(request).send().await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await?;
+        Ok(Response {
+            status,
+            body: body.to_vec()
+        })
+    }
+
+    async fn send_streaming(&self, request: Request) -> Result<StreamingResponse, Error> {
+        let response = self.request(request).send().await?;
+        let status = response.status().as_u16();
+        let body = stream::unfold(Some(response), |response| async move {
+            let mut response = response?;
+            match response.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk.to_vec()), Some(response))),
+                Ok(None) => None,
+                Err(e) => Some((Err(Error::from(e)), None)),
+            }
+        });
+        Ok(StreamingResponse {
+            status,
+            body: body.boxed()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::TryStreamExt;
+
+    struct Echo;
+
+    #[async_trait]
+    impl Transport for Echo {
+        async fn send(&self, request: Request) -> Result<Response, Error> {
+            let path = request.path().as_bytes().to_vec();
+            Ok(Response {
+                status: 200,
+                body: request.body.unwrap_or(path)
+            })
+        }
+    }
+
+    #[test]
+    fn request_path_should_strip_host() {
+        assert_eq!(Request::get("http://127.0.0.1:30001/getAccount/admin/true".to_string()).path(), "getAccount/admin/true");
+        assert_eq!(Request::get("node/getChainInfo".to_string()).path(), "getChainInfo");
+        assert_eq!(Request::get("https://api.iost.io".to_string()).path(), "");
+    }
+
+    #[tokio::test]
+    async fn send_streaming_should_default_to_one_chunk() {
+        let response = Echo.send_streaming(Request::post("memory/subscribe".to_string(), b"{}".to_vec())).await.unwrap();
+        assert_eq!(response.status, 200);
+        let chunks: Vec<Vec<u8>> = response.body.try_collect().await.unwrap();
+        assert_eq!(chunks, vec![b"{}".to_vec()]);
+    }
+}
@@ -0,0 +1,329 @@
+use iost_core::Action;
+use crate::amount::Amount;
+use iost_core::AmountLimit;
+use crate::contracts::ContractCall;
+use crate::error::Error;
+use crate::number::Decimal;
+use crate::status_code::StatusCode;
+use iost_core::Tx;
+use crate::transport::Transport;
+use crate::IOST;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Minimum gas limit accepted by IOST nodes
+pub const MIN_GAS_LIMIT: f64 = 50000.0;
+/// Gas limit of a new `TxBuilder`
+pub const DEFAULT_GAS_LIMIT: f64 = 1000000.0;
+/// Lowest gas ratio accepted by IOST nodes
+pub const MIN_GAS_RATIO: f64 = 1.0;
+/// Highest gas ratio accepted by IOST nodes
+pub const MAX_GAS_RATIO: f64 = 100.0;
+/// Chain id of the IOST mainnet
+pub const MAINNET_CHAIN_ID: u32 = 1024;
+/// Time a transaction of a new `TxBuilder` stays valid
+pub const DEFAULT_EXPIRATION: Duration = Duration::from_secs(90);
+/// Default share of the dry run gas usage added on top of it by `TxBuilder::estimate_gas`
+pub const DEFAULT_GAS_MARGIN: f64 = 0.1;
+/// Amount limit value that lets the transaction spend any amount of a token
+pub const UNLIMITED: &str = "unlimited";
+
+/// Builder of a `Tx`
+///
+/// `time` is taken when the transaction is built unless set explicitly, and `expiration`
+/// is that time plus the configured duration.
+#[derive(Clone, Debug)]
+pub struct TxBuilder {
+    tx: Tx,
+    time: Option<i64>,
+    expiration: Duration,
+    gas_margin: f64,
+    limit_error: Option<String>,
+}
+
+impl TxBuilder {
+    /// A transaction published by `publisher` on mainnet with a gas ratio of 1
+    pub fn new(publisher: &str) -> Self {
+        TxBuilder {
+            tx: Tx {
+                gas_ratio: MIN_GAS_RATIO,
+                gas_limit: DEFAULT_GAS_LIMIT,
+                chain_id: MAINNET_CHAIN_ID,
+                publisher: publisher.to_owned(),
+                ..Default::default()
+            },
+            time: None,
+            expiration: DEFAULT_EXPIRATION,
+            gas_margin: DEFAULT_GAS_MARGIN,
+            limit_error: None
+        }
+    }
+
+    /// Continue building an existing transaction, keeping its `time` and `expiration`
+    pub fn from_tx(tx: Tx) -> Self {
+        let expiration = Duration::from_nanos((tx.expiration - tx.time).max(0) as u64);
+        TxBuilder {
+            time: Some(tx.time),
+            tx,
+            expiration,
+            gas_margin: DEFAULT_GAS_MARGIN,
+            limit_error: None
+        }
+    }
+
+    /// Take `chain_id` from `getChainInfo` and `gas_ratio` from `getGasRatio` of the node
+    pub async fn with_chain_defaults<T: Transport>(mut self, iost: &IOST<T>) -> Result<Self, Error> {
+        let chain_info = iost.get_chain_info().await?;
+        let gas_ratio = iost.get_gas_ratio().await?;
+        self.tx.chain_id = chain_info.chain_id;
+        self.tx.gas_ratio = gas_ratio.lowest_gas_ratio.clamp(MIN_GAS_RATIO, MAX_GAS_RATIO);
+        Ok(self)
+    }
+
+    pub fn publisher(mut self, publisher: &str) -> Self {
+        self.tx.publisher = publisher.to_owned();
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: u32) -> Self {
+        self.tx.chain_id = chain_id;
+        self
+    }
+
+    pub fn gas_ratio(mut self, gas_ratio: f64) -> Self {
+        self.tx.gas_ratio = gas_ratio;
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: f64) -> Self {
+        self.tx.gas_limit = gas_limit;
+        self
+    }
+
+    /// Time of the transaction in nanoseconds since the Unix epoch
+    pub fn time(mut self, time: i64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// How long after its time the transaction stays valid
+    pub fn expiration(mut self, expiration: Duration) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    /// Delay the execution of the transaction
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.tx.delay = delay.as_nanos() as i64;
+        self
+    }
+
+    pub fn action(mut self, action: Action) -> Self {
+        self.tx.actions.push(action);
+        self
+    }
+
+    pub fn actions<I: IntoIterator<Item = Action>>(mut self, actions: I) -> Self {
+        self.tx.actions.extend(actions);
+        self
+    }
+
+    /// Limit the amount of `token` the transaction may spend, `"unlimited"` for no limit
+    pub fn amount_limit(mut self, token: &str, value: &str) -> Self {
+        self.tx.amount_limit.push(AmountLimit {
+            token: token.to_owned(),
+            value: value.to_owned()
+        });
+        self
+    }
+
+    /// Limit the transaction to spending at most `amount`
+    pub fn amount_limit_of(mut self, amount: &Amount) -> Self {
+        self.tx.amount_limit.push(AmountLimit::from(amount));
+        self
+    }
+
+    /// Add a system contract call and its amount limits, summing the limits of the same token.
+    /// Limits that cannot be summed make `build` fail.
+    pub fn call(mut self, call: ContractCall) -> Self {
+        self.tx.actions.push(call.action);
+        for limit in call.amount_limits {
+            match self.tx.amount_limit.iter_mut().find(|l| l.token == limit.token) {
+                Some(existing) => match sum_limits(&existing.value, &limit.value) {
+                    Some(sum) => existing.value = sum,
+                    None => {
+                        let error = format!("cannot sum amount limits {} and {} of {}", existing.value, limit.value, limit.token);
+                        self.limit_error.get_or_insert(error);
+                    }
+                },
+                None => self.tx.amount_limit.push(limit),
+            }
+        }
+        self
+    }
+
+    pub fn signer(mut self, signer: &str) -> Self {
+        self.tx.signers.push(signer.to_owned());
+        self
+    }
+
+    /// Share of the dry run gas usage added as a safety margin, e.g. `0.2` for 20%
+    pub fn gas_margin(mut self, gas_margin: f64) -> Self {
+        self.gas_margin = gas_margin;
+        self
+    }
+
+    /// Dry run the transaction with `execTx` and set `gas_limit` to its gas usage plus the margin.
+    /// Signatures made before are cleared since they no longer cover the transaction.
+    pub async fn estimate_gas<T: Transport>(mut self, iost: &IOST<T>) -> Result<Self, Error> {
+        let tx = self.clone().build()?;
+        let receipt = iost.exec_tx(&tx).await?;
+        if receipt.status_code != StatusCode::SUCCESS {
+            return Err(Error::DryRunFailed(Box::new(receipt)));
+        }
+        self.tx.gas_limit = gas_limit_with_margin(receipt.gas_usage, self.gas_margin);
+        self.tx.publisher_sigs.clear();
+        self.tx.signatures.clear();
+        Ok(self)
+    }
+
+    /// Stamp the time of the transaction and check it against the IOST constraints
+    pub fn build(mut self) -> Result<Tx, Error> {
+        if let Some(error) = self.limit_error {
+            return Err(Error::InvalidTx(error));
+        }
+        if self.tx.gas_limit < MIN_GAS_LIMIT {
+            return Err(Error::InvalidTx(format!("gas_limit {} is below {}", self.tx.gas_limit, MIN_GAS_LIMIT)));
+        }
+        if !(MIN_GAS_RATIO..=MAX_GAS_RATIO).contains(&self.tx.gas_ratio) {
+            return Err(Error::InvalidTx(format!("gas_ratio {} is not in [{}, {}]", self.tx.gas_ratio, MIN_GAS_RATIO, MAX_GAS_RATIO)));
+        }
+        if self.tx.publisher.is_empty() {
+            return Err(Error::InvalidTx("publisher is empty".to_owned()));
+        }
+        let time = self.time.unwrap_or_else(now);
+        self.tx.time = time;
+        self.tx.expiration = time + self.expiration.as_nanos() as i64;
+        Ok(self.tx)
+    }
+}
+
+/// Current time in nanoseconds since the Unix epoch, as in `Tx::time`
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is after the Unix epoch; qed")
+        .as_nanos() as i64
+}
+
+/// Sum of two amount limit values with the digits after the decimal point of the more precise
+/// one, `UNLIMITED` when either is. `None` when a value is not a number or on overflow.
+fn sum_limits(a: &str, b: &str) -> Option<String> {
+    if a == UNLIMITED || b == UNLIMITED {
+        return Some(UNLIMITED.to_string());
+    }
+    let scale = |value: &str| value.find('.').map_or(0, |dot| value.len() - dot - 1);
+    let sum = a.parse::<Decimal>().ok()?.checked_add(&b.parse().ok()?)?;
+    Some(format!("{:.*}", scale(a).max(scale(b)), sum))
+}
+
+fn gas_limit_with_margin(gas_usage: f64, gas_margin: f64) -> f64 {
+    (gas_usage * (1.0 + gas_margin)).ceil().max(MIN_GAS_LIMIT)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::contracts;
+
+    #[test]
+    fn gas_limit_with_margin_should_be_ok() {
+        assert_eq!(gas_limit_with_margin(100000.0, 0.5), 150000.0);
+        assert_eq!(gas_limit_with_margin(123456.0, 0.0), 123456.0);
+        assert_eq!(gas_limit_with_margin(60000.5, 0.0), 60001.0);
+        assert_eq!(gas_limit_with_margin(1000.0, 0.5), MIN_GAS_LIMIT);
+    }
+
+    #[test]
+    fn tx_builder_should_be_ok() {
+        let tx = TxBuilder::new("admin")
+            .time(1_000_000_000)
+            .expiration(Duration::from_secs(90))
+            .delay(Duration::from_secs(1))
+            .gas_ratio(2.0)
+            .action(Action::new("token.iost".to_string(), "transfer".to_string(), r#"["iost","admin","lispczz3","1.000",""]"#.to_string()))
+            .amount_limit("iost", "1.000")
+            .amount_limit_of(&Amount::parse("2", 6, "usdt").unwrap())
+            .signer("lispczz3")
+            .build()
+            .unwrap();
+        assert_eq!(tx.time, 1_000_000_000);
+        assert_eq!(tx.expiration, 91_000_000_000);
+        assert_eq!(tx.delay, 1_000_000_000);
+        assert_eq!(tx.gas_ratio, 2.0);
+        assert_eq!(tx.gas_limit, DEFAULT_GAS_LIMIT);
+        assert_eq!(tx.chain_id, MAINNET_CHAIN_ID);
+        assert_eq!(tx.actions.len(), 1);
+        assert_eq!(tx.amount_limit[0].token, "iost");
+        assert_eq!(tx.amount_limit[1].value, "2.000000");
+        assert_eq!(tx.signers, vec!["lispczz3".to_string()]);
+    }
+
+    #[test]
+    fn tx_builder_should_merge_call_amount_limits() {
+        let iost = |value| Amount::parse(value, 8, "iost").unwrap();
+        let tx = TxBuilder::new("admin")
+            .call(contracts::token::transfer(&iost("1.5"), "admin", "lispczz3", ""))
+            .call(contracts::gas::pledge("admin", "admin", &iost("10")))
+            .call(contracts::ram::lend("admin", "lispczz3", 1024))
+            .build()
+            .unwrap();
+        assert_eq!(tx.actions.len(), 3);
+        assert_eq!(tx.amount_limit.len(), 2);
+        assert_eq!(tx.amount_limit[0].value, "11.50000000");
+        assert_eq!(tx.amount_limit[1].token, "ram");
+        assert_eq!(sum_limits("1", UNLIMITED).unwrap(), UNLIMITED);
+        assert_eq!(sum_limits("1.5", "2.25").unwrap(), "3.75");
+        assert_eq!(sum_limits("5", "10.00000000").unwrap(), "15.00000000");
+    }
+
+    #[test]
+    fn tx_builder_should_reject_limits_it_cannot_sum() {
+        let limits = |a: &str, b: &str| ContractCall {
+            action: Action::new("token.iost".to_string(), "transfer".to_string(), "[]".to_string()),
+            amount_limits: vec![
+                AmountLimit { token: "iost".to_string(), value: a.to_string() },
+                AmountLimit { token: "iost".to_string(), value: b.to_string() }
+            ]
+        };
+        assert!(matches!(TxBuilder::new("admin").call(limits("1", "abc")).build(), Err(Error::InvalidTx(_))));
+        let max = i128::MAX.to_string();
+        assert!(matches!(TxBuilder::new("admin").call(limits(&max, &max)).build(), Err(Error::InvalidTx(_))));
+    }
+
+    #[test]
+    fn tx_builder_should_stamp_time() {
+        let before = now();
+        let tx = TxBuilder::new("admin").build().unwrap();
+        assert!(tx.time >= before);
+        assert_eq!(tx.expiration - tx.time, DEFAULT_EXPIRATION.as_nanos() as i64);
+    }
+
+    #[test]
+    fn tx_builder_from_tx_should_keep_tx() {
+        let tx = Tx { time: 10, expiration: 30, gas_ratio: 1.0, gas_limit: 200000.0, publisher: "admin".to_string(), ..Default::default() };
+        let built = TxBuilder::from_tx(tx).gas_margin(0.3).build().unwrap();
+        assert_eq!(built.time, 10);
+        assert_eq!(built.expiration, 30);
+        assert_eq!(built.gas_limit, 200000.0);
+    }
+
+    #[test]
+    fn tx_builder_should_validate() {
+        assert!(TxBuilder::new("admin").gas_limit(49999.0).build().is_err());
+        assert!(TxBuilder::new("admin").gas_limit(MIN_GAS_LIMIT).build().is_ok());
+        assert!(TxBuilder::new("admin").gas_ratio(0.5).build().is_err());
+        assert!(TxBuilder::new("admin").gas_ratio(100.5).build().is_err());
+        assert!(TxBuilder::new("admin").gas_ratio(100.0).build().is_ok());
+        assert!(TxBuilder::new("").build().is_err());
+    }
+}
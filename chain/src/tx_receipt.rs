@@ -1,9 +1,10 @@
 #![allow(dead_code)]
 
+use iost_core::bytes::{append, to_vec};
 use crate::error::Error;
-use crate::message::ErrorMessage;
 use crate::receipts::Receipt;
 use crate::status_code::StatusCode;
+use iost_core::sha3_256;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
@@ -29,25 +30,81 @@ pub struct TxReceipt {
     pub receipts: Vec<Receipt>
 }
 
-async fn get_tx_receipt_json (domain: &str, hash: &str) -> Result<TxReceipt, Error> {
-    let url = format!("{}/getTxReceiptByTxHash/{}", domain, hash);
-    let req = reqwest::get(&url).await.map_err(Error::Reqwest)?;
-    if req.status() == 200 {
-        let rsp = req.json::<TxReceipt>().await.map_err(Error::Reqwest)?;
-        Ok(rsp)
-    } else {
-        let rsp = req.json::<ErrorMessage>().await.map_err(Error::Reqwest)?;
-        Err(Error::ErrorMessage(rsp))
+impl TxReceipt {
+    /// Serialize the receipt into the IOST binary layout, with `ram_usage` sorted by account
+    /// and `gas_usage` in hundredths as the node stores it
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let tx_hash = iost_keys::base58::from(&self.tx_hash)
+            .map_err(|_| Error::InvalidTx(format!("tx_hash {} is not base58", self.tx_hash)))?;
+        let mut ram_usage: Vec<(&String, &i64)> = self.ram_usage.iter().collect();
+        ram_usage.sort();
+        let receipts = self.receipts.iter()
+            .map(|r| to_vec(&(r.func_name.as_str(), r.content.as_str())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Write)?;
+
+        let mut bytes = Vec::new();
+        append(&tx_hash, &mut bytes).map_err(Error::Write)?;
+        append(&((self.gas_usage * 100.0).round() as i64), &mut bytes).map_err(Error::Write)?;
+        append(&(ram_usage.len() as u32), &mut bytes).map_err(Error::Write)?;
+        for (account, ram) in ram_usage {
+            append(account, &mut bytes).map_err(Error::Write)?;
+            append(ram, &mut bytes).map_err(Error::Write)?;
+        }
+        append(&(self.status_code as i32), &mut bytes).map_err(Error::Write)?;
+        append(&self.message, &mut bytes).map_err(Error::Write)?;
+        append(&self.returns, &mut bytes).map_err(Error::Write)?;
+        append(&receipts, &mut bytes).map_err(Error::Write)?;
+        Ok(bytes)
+    }
+
+    /// SHA3-256 hash of `to_bytes`, the leaf of the receipt in `Block::tx_receipt_merkle_hash`
+    pub fn hash(&self) -> Result<[u8; 32], Error> {
+        Ok(sha3_256(&self.to_bytes()?))
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::mock::MockNode;
+
+    fn string_bytes(s: &str) -> Vec<u8> {
+        let mut bytes = (s.len() as u32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(s.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn to_bytes_should_prefix_each_event_receipt_with_its_length() {
+        let receipt = TxReceipt {
+            tx_hash: iost_keys::base58::encode_slice(&[1, 2, 3]),
+            gas_usage: 1.5,
+            ram_usage: HashMap::new(),
+            status_code: StatusCode::SUCCESS,
+            message: String::new(),
+            returns: vec![],
+            receipts: vec![Receipt { func_name: "token.iost/transfer".to_string(), content: "[]".to_string() }]
+        };
+        let event = [string_bytes("token.iost/transfer"), string_bytes("[]")].concat();
+        let expected = [
+            vec![0, 0, 0, 3, 1, 2, 3],
+            150i64.to_be_bytes().to_vec(),
+            vec![0, 0, 0, 0],
+            0i32.to_be_bytes().to_vec(),
+            string_bytes(""),
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 1],
+            (event.len() as u32).to_be_bytes().to_vec(),
+            event
+        ].concat();
+        assert_eq!(receipt.to_bytes().unwrap(), expected);
+    }
 
     #[tokio::test]
     async fn get_tx_receipt_json_should_be_ok() {
-        let response = get_tx_receipt_json("http://api.iost.io", "Dj8bmA4Fx4LHrwLtDB6EEkNbBFU8biENxf55mNaJewYw").await;
+        let node = MockNode::start();
+        let response = node.client().get_tx_receipt_by_tx_hash("Dj8bmA4Fx4LHrwLtDB6EEkNbBFU8biENxf55mNaJewYw").await;
         assert!(response.is_ok());
     }
 }
@@ -5,6 +5,8 @@ use serde::{Serialize, Deserialize};
 pub struct TxResponse {
     /// Hash of transaction
     pub hash: String,
-    /// The receipt of the transaction pre executed by the RPC node requires the RPC node to turn on the pre execution switch to return this field
-    pub pre_tx_receipt: TxReceipt
+    /// The receipt of the transaction pre executed by the RPC node requires the RPC node to turn on the pre execution switch to return this field.
+    /// `None` as well when `IOST::send_tx` learned from a retry that the transaction was already sent
+    #[serde(default)]
+    pub pre_tx_receipt: Option<TxReceipt>
 }
@@ -1,3 +1,4 @@
+use crate::number::Decimal;
 use serde::{Serialize, Deserialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -5,7 +6,7 @@ pub struct VoteInfo {
     /// candidate
     pub option: String,
     /// number of votes
-    pub votes: String,
+    pub votes: Decimal,
     /// number of votes cleared
-    pub cleared_votes: String
+    pub cleared_votes: Decimal
 }
@@ -0,0 +1,148 @@
+use serde::{Serialize, Deserialize};
+use iost_derive::{Read, Write, NumberBytes};
+use crate::bytes::{Write, NumberBytes};
+use crate::Error;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+#[derive(Clone, Default, Debug, Read, Serialize, Deserialize, Write, PartialEq, NumberBytes)]
+#[iost_root_path = "crate"]
+pub struct Action {
+    /// contract name
+    pub contract: String,
+    /// function name of the contract
+    pub action_name: String,
+    /// Specific parameters of the call. Put every parameter in an array, and JSON-serialize this array. It may looks like ["a_string", 13]
+    pub data: String
+}
+
+impl Action {
+    pub fn new(contract: String, action_name: String, data: String) -> Self {
+        Action {
+            contract,
+            action_name,
+            data
+        }
+    }
+
+    /// Build a call with `args` JSON-serialized into `data`. `args` must serialize to an array,
+    /// e.g. a tuple or a `Vec`
+    pub fn with_args<T: Serialize>(contract: &str, action_name: &str, args: &T) -> Result<Self, Error> {
+        let value = serde_json::to_value(args).map_err(Error::Json)?;
+        if !value.is_array() {
+            return Err(Error::InvalidActionData(value.to_string()));
+        }
+        Ok(Action {
+            contract: contract.to_string(),
+            action_name: action_name.to_string(),
+            data: value.to_string()
+        })
+    }
+
+    /// The call arguments held in `data`
+    pub fn args(&self) -> Result<Vec<serde_json::Value>, Error> {
+        serde_json::from_str(&self.data).map_err(Error::Json)
+    }
+}
+
+impl core::fmt::Display for Action {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f,"contract: {}\n\
+            action_name: {}\n\
+            data: {}",
+            self.contract,
+            self.action_name,
+            self.data
+        )
+    }
+}
+
+pub trait ToAction: Write + NumberBytes {
+    const NAME: u64;
+
+    #[inline]
+    fn to_action(
+        &self,
+        contract: String,
+        action_name: String,
+        data: String
+    ) -> core::result::Result<Action, Error>{
+        let mut data = vec![0_u8; self.num_bytes()];
+        self.write(&mut data, &mut 0).unwrap();
+
+        Ok(Action{
+            contract,
+            action_name,
+            data: "".to_string()
+        })
+    }
+}
+
+impl FromStr for Action {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(Error::Json)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_action() {
+        let action = Action{
+            contract: "iost".to_string(),
+            action_name: "iost".to_string(),
+            data: "iost".to_string()
+        };
+        assert_eq!(action, Action::new("iost".to_string(), "iost".to_string(), "iost".to_string()));
+        assert_eq!(action.to_string(), "contract: iost\naction_name: iost\ndata: iost");
+    }
+
+    #[test]
+    fn test_action_deserialize_should_be_ok1() {
+        let action_str = r#"
+        {
+            "contract": "token.iost",
+            "action_name": "transfer",
+            "data": "["iost", "testaccount", "anothertest", "100", "this is an example transfer"]"
+        }
+        "#;
+        let result_action: Result<Action, _> = serde_json::from_str(action_str);
+        assert!(result_action.is_err());
+    }
+
+    #[test]
+    fn test_action_deserialize_should_be_ok() {
+        let action_str = r#"
+        {
+            "contract": "token.iost",
+            "action_name": "transfer",
+            "data": "[\"iost\", \"testaccount\", \"anothertest\", \"100\", \"this is an example transfer\"]"
+        }
+        "#;
+        let action = Action::from_str(action_str).unwrap();
+        assert_eq!(action.contract, "token.iost");
+        assert_eq!(action.action_name, "transfer");
+        let args = action.args().unwrap();
+        assert_eq!(args.len(), 5);
+        assert_eq!(args[3], "100");
+    }
+
+    #[test]
+    fn test_action_with_args_should_be_ok() {
+        let action = Action::with_args("token.iost", "transfer", &("iost", "testaccount", "anothertest", "100", "memo")).unwrap();
+        assert_eq!(action.data, r#"["iost","testaccount","anothertest","100","memo"]"#);
+        assert_eq!(action.args().unwrap()[1], "testaccount");
+
+        let action = Action::with_args("vote_producer.iost", "vote", &serde_json::json!(["admin", "producer", 100])).unwrap();
+        assert_eq!(action.args().unwrap()[2], 100);
+
+        let action = Action::with_args("token.iost", "transfer", &"iost");
+        assert!(action.is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Serialize, Deserialize};
+use iost_derive::{Read, Write, NumberBytes};
+use alloc::string::String;
+
+#[derive(Clone , Default, Serialize, Deserialize, Debug, PartialEq, Write, Read, NumberBytes)]
+#[iost_root_path = "crate"]
+pub struct AmountLimit {
+    /// token name
+    pub token: String,
+    /// corresponding token limit
+    pub value: String
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_amount_limit_deserialize_should_be_ok() {
+        let amount_limit: AmountLimit = serde_json::from_str(r#"{"token": "iost", "value": "100"}"#).unwrap();
+        assert_eq!(amount_limit.token, "iost");
+        assert_eq!(amount_limit.value, "100");
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(unused_imports)]
 
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 use iost_derive::{Read, Write};
 use crate::unsigned_int::UnsignedInt;
@@ -106,6 +107,26 @@ macro_rules! impl_num {
     )*)
 }
 
+/// Append the binary representation of `value` to the end of `bytes`.
+pub fn append<T>(value: &T, bytes: &mut Vec<u8>) -> Result<(), WriteError>
+    where
+        T: Write + NumberBytes,
+{
+    let mut pos = bytes.len();
+    bytes.resize(pos + value.num_bytes(), 0);
+    value.write(bytes, &mut pos)
+}
+
+/// Serialize `value` into a new buffer of exactly the right size.
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, WriteError>
+    where
+        T: Write + NumberBytes,
+{
+    let mut bytes = Vec::new();
+    append(value, &mut bytes)?;
+    Ok(bytes)
+}
+
 impl_num!(
     u8, 1
     u16, 2
@@ -221,17 +242,18 @@ impl Write for char {
     }
 }
 
+/// Lengths are encoded by IOST as a fixed-width 32-bit big-endian integer.
 impl NumberBytes for usize {
     #[inline]
     fn num_bytes(&self) -> usize {
-        UnsignedInt::from(*self).num_bytes()
+        (*self as u32).num_bytes()
     }
 }
 
 impl Read for usize {
     #[inline]
     fn read(bytes: &[u8], pos: &mut usize) -> Result<Self, ReadError> {
-        UnsignedInt::read(bytes, pos).map(core::convert::Into::into)
+        u32::read(bytes, pos).map(|v| v as Self)
     }
 }
 
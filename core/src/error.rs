@@ -0,0 +1,76 @@
+use crate::bytes::{ReadError, WriteError};
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    ///Error binary deserialization
+    Read(ReadError),
+    ///Error binary serialization
+    Write(WriteError),
+    ///Error key operation
+    Keys(iost_keys::error::Error),
+    ///Error JSON decoding
+    Json(serde_json::Error),
+    ///Error base64 decoding
+    Base64(base64::DecodeError),
+    ///Error action data that is not a JSON array
+    InvalidActionData(String),
+    ///Error unsupported signature algorithm
+    UnsupportedAlgorithm(String)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Read(e) => write!(f, "binary deserialization failed: {:?}", e),
+            Error::Write(e) => write!(f, "binary serialization failed: {:?}", e),
+            Error::Keys(e) => write!(f, "key operation failed: {}", e),
+            Error::Json(e) => write!(f, "JSON decoding failed: {}", e),
+            Error::Base64(e) => write!(f, "base64 decoding failed: {}", e),
+            Error::InvalidActionData(data) => write!(f, "action data is not a JSON array: {}", data),
+            Error::UnsupportedAlgorithm(algorithm) => write!(f, "unsupported signature algorithm: {}", algorithm),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) => Some(e),
+            Error::Base64(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(e: ReadError) -> Error {
+        Error::Read(e)
+    }
+}
+
+impl From<WriteError> for Error {
+    fn from(e: WriteError) -> Error {
+        Error::Write(e)
+    }
+}
+
+impl From<iost_keys::error::Error> for Error {
+    fn from(e: iost_keys::error::Error) -> Error {
+        Error::Keys(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Error {
+        Error::Base64(e)
+    }
+}
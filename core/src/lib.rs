@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use crate::bytes::*;
+pub use crate::action::{Action, ToAction};
+pub use crate::amount_limit::AmountLimit;
+pub use crate::bytes::{ReadError, WriteError};
+pub use crate::error::Error;
+pub use crate::signature::Signature;
+pub use crate::tx::{sha3_256, Tx};
+pub use crate::unsigned_int::UnsignedInt;
+
+mod action;
+mod amount_limit;
+pub mod bytes;
+mod error;
+mod signature;
+mod tx;
+mod unsigned_int;
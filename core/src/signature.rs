@@ -0,0 +1,181 @@
+use serde::{Serialize, Deserialize};
+use iost_derive::{Read, Write};
+use crate::bytes::to_vec;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use iost_keys::algorithm::KeyAlgorithm;
+use iost_keys::ed25519;
+use iost_keys::keypair::Keypair;
+use iost_keys::public::PublicKey;
+use crate::Error;
+
+#[derive(Clone ,Default, Serialize, Deserialize, Debug, PartialEq, Write, Read)]
+#[iost_root_path = "crate"]
+pub struct Signature {
+    /// Encryption algorithm. Currently only "ed25519" and "secp256k1" are supported
+    pub algorithm: String,
+    /// After the contract is serialized, Sha3 is used for hash, and then private key is used for signature. Base64 encoding. See corresponding documents for details
+    pub signature: String,
+    /// The public key used by this signature. Base64 encoding
+    pub public_key: String
+}
+
+impl Signature {
+    /// Sign a transaction hash, see `Tx::base_hash` and `Tx::publish_hash`
+    pub fn sign(hash: &[u8], keypair: &Keypair) -> Result<Self, Error> {
+        let signature = keypair.sign_hash(hash).map_err(Error::Keys)?;
+        Ok(Signature {
+            algorithm: keypair.algorithm().name().to_string(),
+            signature: base64::encode(&signature[..]),
+            public_key: base64::encode(&keypair.public_key_bytes())
+        })
+    }
+
+    /// Build from a secp256k1 signature and the public key that produced it
+    pub fn from_secp256k1(signature: &iost_keys::signature::Signature, public_key: &PublicKey) -> Self {
+        Signature {
+            algorithm: KeyAlgorithm::Secp256k1.name().to_string(),
+            signature: base64::encode(&signature.sig.serialize()[..]),
+            public_key: base64::encode(&public_key.to_bytes())
+        }
+    }
+
+    /// Build from an ed25519 signature and the public key that produced it
+    pub fn from_ed25519(signature: &ed25519::Signature, public_key: &ed25519::PublicKey) -> Self {
+        Signature {
+            algorithm: KeyAlgorithm::Ed25519.name().to_string(),
+            signature: base64::encode(&signature.to_bytes()[..]),
+            public_key: base64::encode(&public_key.to_bytes())
+        }
+    }
+
+    /// Decode a secp256k1 signature and its public key
+    pub fn to_secp256k1(&self) -> Result<(iost_keys::signature::Signature, PublicKey), Error> {
+        self.expect_algorithm(KeyAlgorithm::Secp256k1)?;
+        let signature = iost_keys::signature::Signature::from_slice(&self.signature_bytes()?).map_err(Error::Keys)?;
+        let public_key = PublicKey::from_slice(&self.public_key_bytes()?).map_err(Error::Keys)?;
+        Ok((signature, public_key))
+    }
+
+    /// Decode an ed25519 signature and its public key
+    pub fn to_ed25519(&self) -> Result<(ed25519::Signature, ed25519::PublicKey), Error> {
+        self.expect_algorithm(KeyAlgorithm::Ed25519)?;
+        let signature = ed25519::Signature::from_slice(&self.signature_bytes()?).map_err(Error::Keys)?;
+        let public_key = ed25519::PublicKey::from_slice(&self.public_key_bytes()?).map_err(Error::Keys)?;
+        Ok((signature, public_key))
+    }
+
+    /// Verify the signature on a transaction hash against the public key it carries
+    pub fn verify(&self, hash: &[u8]) -> Result<(), Error> {
+        match self.key_algorithm()? {
+            KeyAlgorithm::Secp256k1 => {
+                let (signature, public_key) = self.to_secp256k1()?;
+                public_key.verify_hash(hash, &signature).map_err(Error::Keys)
+            }
+            KeyAlgorithm::Ed25519 => {
+                let (signature, public_key) = self.to_ed25519()?;
+                public_key.verify(hash, &signature).map_err(Error::Keys)
+            }
+        }
+    }
+
+    /// The IOST public key ID of `public_key`, as used in account permissions
+    pub fn public_key_id(&self) -> Result<String, Error> {
+        match self.key_algorithm()? {
+            KeyAlgorithm::Secp256k1 => Ok(self.to_secp256k1()?.1.to_iost_string()),
+            KeyAlgorithm::Ed25519 => Ok(self.to_ed25519()?.1.to_iost_string()),
+        }
+    }
+
+    /// Parsed `algorithm`
+    pub fn key_algorithm(&self) -> Result<KeyAlgorithm, Error> {
+        KeyAlgorithm::from_str(&self.algorithm)
+            .map_err(|_| Error::UnsupportedAlgorithm(self.algorithm.clone()))
+    }
+
+    /// Raw bytes of `signature`
+    pub fn signature_bytes(&self) -> Result<Vec<u8>, Error> {
+        base64::decode(&self.signature).map_err(Error::Base64)
+    }
+
+    /// Raw bytes of `public_key`
+    pub fn public_key_bytes(&self) -> Result<Vec<u8>, Error> {
+        base64::decode(&self.public_key).map_err(Error::Base64)
+    }
+
+    /// IOST binary layout of the signature: algorithm id, raw signature and raw public key
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let algorithm = self.key_algorithm()?.id();
+        to_vec(&(algorithm, self.signature_bytes()?, self.public_key_bytes()?)).map_err(Error::Write)
+    }
+
+    fn expect_algorithm(&self, algorithm: KeyAlgorithm) -> Result<(), Error> {
+        if self.key_algorithm()? == algorithm {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedAlgorithm(self.algorithm.clone()))
+        }
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(Error::Json)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SIGNATURE_JSON: &str = r#"{
+        "algorithm": "ED25519",
+        "signature": "/K1HM0OEbfJ4+D3BmalpLmb03WS7BeCz4nVHBNbDrx3/A31aN2RJNxyEKhv+VSoWctfevDNRnL1kadRVxSt8CA==",
+        "public_key": "lDS+SdM+aiVHbDyXapvrsgyKxFg9mJuHWPZb/INBRWY="
+    }"#;
+
+    #[test]
+    fn test_signature_serde_should_be_ok() {
+        let signature = Signature::from_str(SIGNATURE_JSON).unwrap();
+        assert_eq!(signature.algorithm, "ED25519");
+        assert_eq!(signature.public_key, "lDS+SdM+aiVHbDyXapvrsgyKxFg9mJuHWPZb/INBRWY=");
+
+        let json = serde_json::to_string(&signature).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["algorithm"], "ED25519");
+        assert_eq!(value["signature"], signature.signature.as_str());
+        assert_eq!(value["public_key"], signature.public_key.as_str());
+        assert_eq!(Signature::from_str(&json).unwrap(), signature);
+    }
+
+    #[test]
+    fn test_signature_to_bytes_should_be_ok() {
+        let signature = Signature::from_str(SIGNATURE_JSON).unwrap();
+        let bytes = signature.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 1 + 4 + 64 + 4 + 32);
+        assert_eq!(&bytes[..5], &[2, 0, 0, 0, 64]);
+        assert_eq!(signature.to_ed25519().unwrap().1.to_bytes(), signature.public_key_bytes().unwrap());
+        assert!(signature.to_secp256k1().is_err());
+    }
+
+    #[test]
+    fn test_signature_keys_conversion_should_be_ok() {
+        let hash = [3u8; 32];
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        let signature = Signature::sign(&hash, &keypair).unwrap();
+        let (sig, pk) = signature.to_ed25519().unwrap();
+        assert_eq!(Signature::from_ed25519(&sig, &pk), signature);
+        assert!(signature.verify(&hash).is_ok());
+        assert!(signature.verify(&[4u8; 32]).is_err());
+
+        let keypair = Keypair::from_secret_wif("5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4").unwrap();
+        let signature = Signature::sign(&hash, &keypair).unwrap();
+        let (sig, pk) = signature.to_secp256k1().unwrap();
+        assert_eq!(Signature::from_secp256k1(&sig, &pk), signature);
+        assert!(signature.verify(&hash).is_ok());
+        assert!(signature.verify(&[4u8; 32]).is_err());
+    }
+}
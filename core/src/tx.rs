@@ -0,0 +1,259 @@
+use crate::action::Action;
+use crate::amount_limit::AmountLimit;
+use crate::bytes::{append, to_vec};
+use crate::error::Error;
+use crate::signature::Signature;
+use alloc::string::String;
+use alloc::vec::Vec;
+use iost_derive::{Read, Write};
+use iost_keys::keypair::Keypair;
+use serde::{Serialize, Deserialize};
+use sha3::{Digest, Sha3_256};
+
+#[derive(Clone ,Default, Serialize, Deserialize, Debug, Write, Read)]
+#[iost_root_path = "crate"]
+pub struct Tx {
+    /// Time of transaction. Unixepoch start in nanoseconds
+    pub time: i64,
+    /// Transaction expiration time. Unixepoch starts in nanoseconds. If the chunk node does not receive the transaction until after the expiration time, it will not execute
+    pub expiration: i64,
+    /// GAS multiplying rate. This transaction shall be paid according to the gas ratio of the default gas. The higher the multiplier, the higher the priority. The reasonable value range is [1.0, 100.0]
+    pub gas_ratio: f64,
+    /// The maximum allowed gas of the transaction, with a minimum setting of 50000
+    pub gas_limit: f64,
+    /// Used in delayed transactions. The number of nanoseconds to delay execution. Non delayed transaction set to 0
+    pub delay: i64,
+    /// Network ID
+    pub chain_id: u32,
+    /// Specific call in transaction
+    pub actions: Vec<Action>,
+    /// Token restrictions on transactions. You can specify multiple tokens and a corresponding number limit. If the transaction exceeds these limits, execution fails
+    pub amount_limit: Vec<AmountLimit>,
+    /// ID of the transaction sender
+    pub publisher: String,
+    /// Publisher's signature. The signing process is as follows. Publisher can provide multiple signatures with different permissions. You can refer to the documentation of the permission system
+    pub publisher_sigs: Vec<Signature>,
+    /// Signer ID other than publisher. It can be empty.
+    pub signers: Vec<String>,
+    /// Signature of signers. Each signer can have one or more signatures, so the length is not less than the length of signers
+    pub signatures: Vec<Signature>
+}
+
+impl Tx {
+    /// Serialize the transaction into the IOST binary layout. Signers' `signatures` are only
+    /// part of the layout when `with_signatures` is set, which is what the publisher signs over.
+    pub fn to_bytes(&self, with_signatures: bool) -> Result<Vec<u8>, Error> {
+        let actions = self.actions.iter()
+            .map(to_vec)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Write)?;
+        let amount_limit = self.amount_limit.iter()
+            .map(to_vec)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::Write)?;
+        let signatures = if with_signatures {
+            let signatures = self.signatures.iter()
+                .map(Signature::to_bytes)
+                .collect::<Result<Vec<_>, _>>()?;
+            Some(signatures)
+        } else {
+            None
+        };
+
+        let mut bytes = Vec::new();
+        append(&self.time, &mut bytes).map_err(Error::Write)?;
+        append(&self.expiration, &mut bytes).map_err(Error::Write)?;
+        append(&((self.gas_ratio * 100.0) as i64), &mut bytes).map_err(Error::Write)?;
+        append(&((self.gas_limit * 100.0) as i64), &mut bytes).map_err(Error::Write)?;
+        append(&self.delay, &mut bytes).map_err(Error::Write)?;
+        append(&self.chain_id, &mut bytes).map_err(Error::Write)?;
+        // reserved
+        append(&Vec::<u8>::new(), &mut bytes).map_err(Error::Write)?;
+        append(&self.signers, &mut bytes).map_err(Error::Write)?;
+        append(&actions, &mut bytes).map_err(Error::Write)?;
+        append(&amount_limit, &mut bytes).map_err(Error::Write)?;
+        if let Some(signatures) = signatures {
+            append(&signatures, &mut bytes).map_err(Error::Write)?;
+        }
+        Ok(bytes)
+    }
+
+    /// SHA3-256 hash signed by every account in `signers`
+    pub fn base_hash(&self) -> Result<[u8; 32], Error> {
+        Ok(sha3_256(&self.to_bytes(false)?))
+    }
+
+    /// SHA3-256 hash signed by the publisher, covering the signers' `signatures` as well
+    pub fn publish_hash(&self) -> Result<[u8; 32], Error> {
+        Ok(sha3_256(&self.to_bytes(true)?))
+    }
+
+    /// SHA3-256 hash identifying the signed transaction, over the publish layout followed by
+    /// `publisher` and `publisher_sigs`. Its base58 is the hash the node reports.
+    pub fn hash(&self) -> Result<[u8; 32], Error> {
+        let publisher_sigs = self.publisher_sigs.iter()
+            .map(Signature::to_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut bytes = self.to_bytes(true)?;
+        append(&self.publisher, &mut bytes).map_err(Error::Write)?;
+        append(&publisher_sigs, &mut bytes).map_err(Error::Write)?;
+        Ok(sha3_256(&bytes))
+    }
+
+    /// Sign the transaction on behalf of one of `signers`
+    pub fn sign_as_signer(&mut self, keypair: &Keypair) -> Result<(), Error> {
+        let signature = Signature::sign(&self.base_hash()?, keypair)?;
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    /// Sign the transaction on behalf of `publisher`. Signers must have signed before this is called
+    pub fn sign_as_publisher(&mut self, keypair: &Keypair) -> Result<(), Error> {
+        let signature = Signature::sign(&self.publish_hash()?, keypair)?;
+        self.publisher_sigs.push(signature);
+        Ok(())
+    }
+}
+
+/// SHA3-256 hash of `bytes`, as used for transactions, receipts and block headers
+pub fn sha3_256(bytes: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&Sha3_256::digest(bytes));
+    hash
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use iost_keys::algorithm::KeyAlgorithm;
+
+    #[test]
+    fn test_send_tx_deserialize_should_be_ok() {
+        let tx_str = r#"
+        {
+            "time": 1544709662543340000,
+            "expiration": 1544709692318715000,
+            "gas_ratio": 1,
+            "gas_limit": 500000,
+            "delay": 0,
+            "chain_id": 1024,
+            "signers": [],
+            "actions": [{
+                "contract": "token.iost",
+                "action_name": "transfer",
+                "data": "[\"iost\", \"testaccount\", \"anothertest\", \"100\", \"this is an example transfer\"]"
+            }],
+            "amount_limit": [{
+                "token": "*",
+                "value": "unlimited"
+            }],
+            "signatures": [],
+            "publisher": "testaccount",
+            "publisher_sigs": [{
+                "algorithm": "ED25519",
+                "public_key": "lDS+SdM+aiVHbDyXapvrsgyKxFg9mJuHWPZb/INBRWY=",
+                "signature": "/K1HM0OEbfJ4+D3BmalpLmb03WS7BeCz4nVHBNbDrx3/A31aN2RJNxyEKhv+VSoWctfevDNRnL1kadRVxSt8CA=="
+            }]
+        }
+        "#;
+        let tx_struct: Result<Tx, _> = serde_json::from_str(tx_str);
+        assert!(tx_struct.is_ok());
+    }
+
+    #[test]
+    fn test_tx_to_bytes_should_be_ok() {
+        let tx = Tx {
+            time: 1,
+            expiration: 2,
+            gas_ratio: 1.0,
+            gas_limit: 500000.0,
+            chain_id: 1024,
+            signers: vec!["iost".to_string()],
+            ..Default::default()
+        };
+        let bytes = tx.to_bytes(false).unwrap();
+        assert_eq!(bytes.len(), 8 * 5 + 4 + 4 + 4 + 4 + 4 + 4 + 4);
+        assert_eq!(&bytes[..8], &[0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(&bytes[16..24], &[0, 0, 0, 0, 0, 0, 0, 100]);
+        assert_eq!(&bytes[40..44], &[0, 0, 4, 0]);
+        assert_eq!(&bytes[48..60], &[0, 0, 0, 1, 0, 0, 0, 4, 105, 111, 115, 116]);
+    }
+
+    #[test]
+    fn test_tx_sign_should_be_ok() {
+        let keypair = Keypair::from_secret_wif("5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4").unwrap();
+        let mut tx = Tx {
+            time: 1544709662543340000,
+            expiration: 1544709692318715000,
+            gas_ratio: 1.0,
+            gas_limit: 500000.0,
+            chain_id: 1024,
+            actions: vec![Action::new("token.iost".to_string(), "transfer".to_string(), "[]".to_string())],
+            publisher: "testaccount".to_string(),
+            signers: vec!["anothertest".to_string()],
+            ..Default::default()
+        };
+
+        let base_hash = tx.base_hash().unwrap();
+        tx.sign_as_signer(&keypair).unwrap();
+        assert_eq!(tx.signatures.len(), 1);
+        assert_eq!(tx.base_hash().unwrap(), base_hash);
+
+        let publish_hash = tx.publish_hash().unwrap();
+        assert_ne!(publish_hash, base_hash);
+        tx.sign_as_publisher(&keypair).unwrap();
+        let expected = keypair.sign_hash(&publish_hash).unwrap();
+        assert!(keypair.verify_hash(&publish_hash, &expected).is_ok());
+        assert_eq!(tx.publisher_sigs[0].algorithm, "SECP256K1");
+        assert_eq!(tx.publisher_sigs[0].signature, base64::encode(&expected[..]));
+        assert_eq!(tx.publisher_sigs[0].public_key, base64::encode(&keypair.public_key_bytes()));
+    }
+
+    #[test]
+    fn tx_hash_should_cover_publisher_signatures() {
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        let mut tx = Tx {
+            gas_ratio: 1.0,
+            gas_limit: 500000.0,
+            chain_id: 1024,
+            publisher: "testaccount".to_string(),
+            ..Default::default()
+        };
+        let unsigned = tx.hash().unwrap();
+        tx.sign_as_publisher(&keypair).unwrap();
+        let hash = tx.hash().unwrap();
+        assert_ne!(hash, unsigned);
+        assert_ne!(hash, tx.publish_hash().unwrap());
+
+        let mut bytes = tx.to_bytes(true).unwrap();
+        bytes.extend_from_slice(&[0, 0, 0, 11]);
+        bytes.extend_from_slice(b"testaccount");
+        bytes.extend_from_slice(&[0, 0, 0, 1]);
+        let signature = tx.publisher_sigs[0].to_bytes().unwrap();
+        bytes.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&signature);
+        assert_eq!(hash, sha3_256(&bytes));
+    }
+
+    #[test]
+    fn test_tx_sign_ed25519_should_be_ok() {
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        let mut tx = Tx {
+            gas_ratio: 1.0,
+            gas_limit: 500000.0,
+            chain_id: 1024,
+            publisher: "testaccount".to_string(),
+            ..Default::default()
+        };
+        tx.sign_as_publisher(&keypair).unwrap();
+
+        let signature = &tx.publisher_sigs[0];
+        assert_eq!(signature.algorithm, "ED25519");
+        assert_eq!(base64::decode(&signature.signature).unwrap().len(), 64);
+        assert_eq!(base64::decode(&signature.public_key).unwrap().len(), 32);
+        let raw = base64::decode(&signature.signature).unwrap();
+        assert!(keypair.verify_hash(&tx.publish_hash().unwrap(), &raw).is_ok());
+        assert_eq!(signature.to_bytes().unwrap()[0], 2);
+    }
+}
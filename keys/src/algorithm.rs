@@ -0,0 +1,80 @@
+use core::{fmt, str::FromStr};
+use crate::error;
+
+/// The signature algorithms supported by IOST accounts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeyAlgorithm {
+    /// secp256k1, as used by Bitcoin and EOS
+    Secp256k1,
+    /// ed25519, the default algorithm of IOST accounts
+    Ed25519,
+}
+
+impl KeyAlgorithm {
+    /// Id of the algorithm in the IOST binary encoding of a signature
+    pub fn id(&self) -> u8 {
+        match *self {
+            KeyAlgorithm::Secp256k1 => 1,
+            KeyAlgorithm::Ed25519 => 2,
+        }
+    }
+
+    /// Name of the algorithm in the IOST JSON encoding of a signature
+    pub fn name(&self) -> &'static str {
+        match *self {
+            KeyAlgorithm::Secp256k1 => "SECP256K1",
+            KeyAlgorithm::Ed25519 => "ED25519",
+        }
+    }
+
+    /// Parse the id of the algorithm in the IOST binary encoding of a signature
+    pub fn from_id(id: u8) -> crate::Result<KeyAlgorithm> {
+        match id {
+            1 => Ok(KeyAlgorithm::Secp256k1),
+            2 => Ok(KeyAlgorithm::Ed25519),
+            _ => Err(error::Error::UnsupportedAlgorithm),
+        }
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for KeyAlgorithm {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> crate::Result<KeyAlgorithm> {
+        if s.eq_ignore_ascii_case("secp256k1") {
+            Ok(KeyAlgorithm::Secp256k1)
+        } else if s.eq_ignore_ascii_case("ed25519") {
+            Ok(KeyAlgorithm::Ed25519)
+        } else {
+            Err(error::Error::UnsupportedAlgorithm)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::KeyAlgorithm;
+    use core::str::FromStr;
+    use alloc::string::ToString;
+
+    #[test]
+    fn algorithm_from_str_should_work() {
+        assert_eq!(KeyAlgorithm::from_str("ED25519"), Ok(KeyAlgorithm::Ed25519));
+        assert_eq!(KeyAlgorithm::from_str("secp256k1"), Ok(KeyAlgorithm::Secp256k1));
+        assert!(KeyAlgorithm::from_str("rsa").is_err());
+        assert_eq!(KeyAlgorithm::Ed25519.to_string(), "ED25519");
+    }
+
+    #[test]
+    fn algorithm_id_should_work() {
+        assert_eq!(KeyAlgorithm::from_id(KeyAlgorithm::Secp256k1.id()), Ok(KeyAlgorithm::Secp256k1));
+        assert_eq!(KeyAlgorithm::from_id(KeyAlgorithm::Ed25519.id()), Ok(KeyAlgorithm::Ed25519));
+        assert!(KeyAlgorithm::from_id(0).is_err());
+    }
+}
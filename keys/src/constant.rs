@@ -26,3 +26,12 @@ pub const MAX_SIGNATURE_SIZE: usize = 72;
 
 /// The maximum size of a compact signature
 pub const COMPACT_SIGNATURE_SIZE: usize = 64;
+
+/// The size (in bytes) of an ed25519 secret key
+pub const ED25519_SECRET_KEY_SIZE: usize = 32;
+
+/// The size (in bytes) of an ed25519 public key
+pub const ED25519_PUBLIC_KEY_SIZE: usize = 32;
+
+/// The size (in bytes) of an ed25519 keypair, as exported by iwallet
+pub const ED25519_KEYPAIR_SIZE: usize = ED25519_SECRET_KEY_SIZE + ED25519_PUBLIC_KEY_SIZE;
@@ -0,0 +1,233 @@
+//! Ed25519 keys and signatures, the default algorithm of IOST accounts
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use crate::constant::*;
+use crate::base58;
+use crate::error;
+use ed25519_dalek::Verifier;
+use rand::Rng;
+
+/// An ed25519 private key
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey {
+    /// The 32 byte seed of the key
+    pub seed: [u8; ED25519_SECRET_KEY_SIZE],
+}
+
+impl SecretKey {
+    /// Creates a new random secret key.
+    pub fn generate<R>(csprng: &mut R) -> Self where R: Rng {
+        let mut seed = [0u8; ED25519_SECRET_KEY_SIZE];
+        csprng.fill_bytes(&mut seed);
+
+        SecretKey { seed }
+    }
+
+    /// Serialize the private key to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.seed.to_vec()
+    }
+
+    /// Deserialize a secret key from its 32 byte seed
+    pub fn from_slice(data: &[u8]) -> crate::Result<SecretKey> {
+        if data.len() != ED25519_SECRET_KEY_SIZE {
+            return Err(base58::Error::InvalidLength(data.len()).into());
+        }
+        let mut seed = [0u8; ED25519_SECRET_KEY_SIZE];
+        seed.copy_from_slice(data);
+
+        Ok(SecretKey { seed })
+    }
+
+    /// Serialize the private key the way iwallet exports it: base58 of the seed followed by the public key
+    pub fn to_iost_string(&self) -> String {
+        let mut data = self.to_bytes();
+        data.extend_from_slice(&PublicKey::from(self).to_bytes());
+
+        base58::encode_slice(&data)
+    }
+
+    /// Parse a private key exported by iwallet. The bare 32 byte seed is accepted as well.
+    pub fn from_iost_string(s: &str) -> crate::Result<SecretKey> {
+        let data = base58::from(s)?;
+        match data.len() {
+            ED25519_SECRET_KEY_SIZE => SecretKey::from_slice(&data),
+            ED25519_KEYPAIR_SIZE => {
+                let sk = SecretKey::from_slice(&data[..ED25519_SECRET_KEY_SIZE])?;
+                if PublicKey::from(&sk).to_bytes()[..] != data[ED25519_SECRET_KEY_SIZE..] {
+                    return Err(error::Error::Ed25519);
+                }
+                Ok(sk)
+            }
+            len => Err(base58::Error::InvalidLength(len).into()),
+        }
+    }
+
+    /// Sign a message with secret key. IOST signs transaction hashes directly.
+    pub fn sign(&self, message: &[u8]) -> crate::Result<Signature> {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&self.seed)
+            .map_err(|_| error::Error::Ed25519)?;
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let sig = ed25519_dalek::ExpandedSecretKey::from(&secret).sign(message, &public);
+
+        Ok(Signature { sig })
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[private key data]")
+    }
+}
+
+/// An ed25519 public key
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PublicKey {
+    /// The actual ed25519 key
+    pub key: ed25519_dalek::PublicKey,
+}
+
+impl PublicKey {
+    /// Serialize the public key to bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.key.to_bytes().to_vec()
+    }
+
+    /// Deserialize a public key from a slice
+    pub fn from_slice(data: &[u8]) -> crate::Result<PublicKey> {
+        if data.len() != ED25519_PUBLIC_KEY_SIZE {
+            return Err(base58::Error::InvalidLength(data.len()).into());
+        }
+        let key = ed25519_dalek::PublicKey::from_bytes(data)
+            .map_err(|_| error::Error::Ed25519)?;
+
+        Ok(PublicKey { key })
+    }
+
+    /// Serialize the public key to an IOST public key ID: base58 of the raw key
+    pub fn to_iost_string(&self) -> String {
+        base58::encode_slice(self.key.as_bytes())
+    }
+
+    /// Parse an IOST public key ID
+    pub fn from_iost_string(s: &str) -> crate::Result<PublicKey> {
+        PublicKey::from_slice(&base58::from(s)?)
+    }
+
+    /// Verify a signature on a message with public key.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> crate::Result<()> {
+        self.key.verify(message, &signature.sig)
+            .map_err(|_| error::Error::VerifyFailed)
+    }
+}
+
+impl<'a> From<&'a SecretKey> for PublicKey {
+    /// Derive this public key from its corresponding `SecretKey`.
+    fn from(sk: &SecretKey) -> PublicKey {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&sk.seed)
+            .expect("seed is always 32 bytes; qed");
+
+        PublicKey { key: ed25519_dalek::PublicKey::from(&secret) }
+    }
+}
+
+/// An ed25519 signature
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Signature {
+    /// The actual ed25519 signature
+    pub sig: ed25519_dalek::Signature,
+}
+
+impl Signature {
+    /// Serialize the signature to its 64 bytes
+    pub fn to_bytes(&self) -> [u8; COMPACT_SIGNATURE_SIZE] {
+        self.sig.to_bytes()
+    }
+
+    /// Deserialize a signature from a slice
+    pub fn from_slice(data: &[u8]) -> crate::Result<Signature> {
+        if data.len() != COMPACT_SIGNATURE_SIZE {
+            return Err(base58::Error::InvalidLength(data.len()).into());
+        }
+        let sig = ed25519_dalek::Signature::try_from(data)
+            .map_err(|_| error::Error::Ed25519)?;
+
+        Ok(Signature { sig })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PublicKey, SecretKey, Signature};
+    use alloc::vec::Vec;
+    use hex::FromHex;
+    #[cfg(feature = "std")]
+    use rand::thread_rng;
+
+    // RFC 8032, section 7.1, test 2
+    const SECRET: &str = "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+    const PUBLIC: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+    const SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                             085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ed25519_generate_should_work() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate(&mut rng);
+        let pk = PublicKey::from(&sk);
+        let sig = sk.sign("hello".as_bytes()).unwrap();
+        assert!(pk.verify("hello".as_bytes(), &sig).is_ok());
+    }
+
+    #[test]
+    fn ed25519_sign_should_work() {
+        let sk = SecretKey::from_slice(&Vec::from_hex(SECRET).unwrap()).unwrap();
+        let pk = PublicKey::from(&sk);
+        assert_eq!(pk.to_bytes(), Vec::from_hex(PUBLIC).unwrap());
+
+        let sig = sk.sign(&[0x72]).unwrap();
+        assert_eq!(sig.to_bytes().to_vec(), Vec::from_hex(SIGNATURE).unwrap());
+        assert_eq!(Signature::from_slice(&sig.to_bytes()).unwrap(), sig);
+    }
+
+    #[test]
+    fn ed25519_verify_should_work() {
+        let pk = PublicKey::from_slice(&Vec::from_hex(PUBLIC).unwrap()).unwrap();
+        let sig = Signature::from_slice(&Vec::from_hex(SIGNATURE).unwrap()).unwrap();
+        assert!(pk.verify(&[0x72], &sig).is_ok());
+        assert_eq!(pk.verify(&[0x73], &sig), Err(crate::error::Error::VerifyFailed));
+    }
+
+    #[test]
+    fn ed25519_iost_string_should_work() {
+        let secret = "2yquS3ySrGWPEKywCPzX4RTJugqRh7kJSo5aehsLYPEWkUxBWA39oMrZ7ZxuM4fgyXYs2cPwh5n8aNNpH5x2VyK1";
+        let sk = SecretKey::from_iost_string(secret).unwrap();
+        assert_eq!(sk.to_iost_string(), secret);
+        let pk = PublicKey::from(&sk);
+        assert_eq!(pk.to_iost_string(), "Gcv8c2tH8qZrUYnKdEEdTtASsxivic2834MQW6mgxqto");
+        assert_eq!(PublicKey::from_iost_string("Gcv8c2tH8qZrUYnKdEEdTtASsxivic2834MQW6mgxqto").unwrap(), pk);
+
+        let seed_only = crate::base58::encode_slice(&sk.to_bytes());
+        assert_eq!(SecretKey::from_iost_string(&seed_only).unwrap(), sk);
+    }
+
+    #[test]
+    fn ed25519_iost_string_should_error() {
+        // public key half does not match the seed
+        let mut data = [7u8; 64];
+        data[63] = 0;
+        assert!(SecretKey::from_iost_string(&crate::base58::encode_slice(&data)).is_err());
+        assert!(SecretKey::from_iost_string("0OIl").is_err());
+    }
+
+    #[test]
+    fn ed25519_from_slice_should_error() {
+        assert!(SecretKey::from_slice(&[0u8; 31]).is_err());
+        assert!(PublicKey::from_slice(&[0u8; 33]).is_err());
+        assert!(Signature::from_slice(&[0u8; 65]).is_err());
+    }
+}
@@ -12,8 +12,12 @@ pub enum Error {
     Secp256k1(secp256k1::Error),
     /// hash error
     Hash(bitcoin_hashes::error::Error),
+    /// ed25519-related error
+    Ed25519,
     /// verify failed
     VerifyFailed,
+    /// unsupported signature algorithm
+    UnsupportedAlgorithm,
 }
 
 impl fmt::Display for Error {
@@ -22,7 +26,9 @@ impl fmt::Display for Error {
             Error::Base58(ref e) => fmt::Display::fmt(e, f),
             Error::Secp256k1(ref e) => f.write_str(&e.to_string()),
             Error::Hash(ref e) => f.write_str(&e.to_string()),
+            Error::Ed25519 => f.write_str("Invalid ed25519 key or signature"),
             Error::VerifyFailed => f.write_str("Verify failed"),
+            Error::UnsupportedAlgorithm => f.write_str("Unsupported signature algorithm"),
         }
     }
 }
@@ -1,26 +1,47 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::algorithm::KeyAlgorithm;
 use crate::constant::*;
+use crate::ed25519;
 use crate::public::PublicKey;
 use crate::secret::SecretKey;
-use crate::signature::Signature;
 use rand::Rng;
 
 
-/// A secp256k1 keypair.
+/// A keypair of one of the algorithms supported by IOST.
 #[derive(Clone, PartialEq, Eq)]
-pub struct Keypair {
-    /// The secret half of this keypair.
-    pub sk: SecretKey,
-    /// The public half of this keypair.
-    pub pk: PublicKey,
+pub enum Keypair {
+    /// A secp256k1 keypair.
+    Secp256k1 {
+        /// The secret half of this keypair.
+        sk: SecretKey,
+        /// The public half of this keypair.
+        pk: PublicKey,
+    },
+    /// An ed25519 keypair.
+    Ed25519 {
+        /// The secret half of this keypair.
+        sk: ed25519::SecretKey,
+        /// The public half of this keypair.
+        pk: ed25519::PublicKey,
+    },
 }
 
 impl Keypair {
-    /// Generate an secp256k1 keypair.
-    pub fn generate<R>(csprng: &mut R) -> Keypair where R: Rng {
-        let sk = SecretKey::generate(csprng);
-        let pk = PublicKey::from(&sk);
-
-        Keypair { sk, pk }
+    /// Generate a keypair of the given algorithm.
+    pub fn generate<R>(algorithm: KeyAlgorithm, csprng: &mut R) -> Keypair where R: Rng {
+        match algorithm {
+            KeyAlgorithm::Secp256k1 => {
+                let sk = SecretKey::generate(csprng);
+                let pk = PublicKey::from(&sk);
+                Keypair::Secp256k1 { sk, pk }
+            }
+            KeyAlgorithm::Ed25519 => {
+                let sk = ed25519::SecretKey::generate(csprng);
+                let pk = ed25519::PublicKey::from(&sk);
+                Keypair::Ed25519 { sk, pk }
+            }
+        }
     }
 
     /// Generate an secp256k1 keypair from secret in WIF format
@@ -28,33 +49,138 @@ impl Keypair {
         let sk = SecretKey::from_wif(wif)?;
         let pk = PublicKey::from(&sk);
 
-        Ok(Keypair { sk, pk })
+        Ok(Keypair::Secp256k1 { sk, pk })
     }
 
-    /// Convert this keypair to bytes.
-    pub fn to_bytes(&self) -> [u8; KEYPAIR_LENGTH] {
-        let mut bytes: [u8; KEYPAIR_LENGTH] = [0u8; KEYPAIR_LENGTH];
+    /// Generate a keypair of the given algorithm from the raw bytes of its secret
+    pub fn from_secret_bytes(algorithm: KeyAlgorithm, data: &[u8]) -> crate::Result<Keypair> {
+        match algorithm {
+            KeyAlgorithm::Secp256k1 => {
+                let key = secp256k1::SecretKey::parse_slice(data)?;
+                let sk = SecretKey { compressed: true, network: crate::network::Network::Mainnet, key };
+                let pk = PublicKey::from(&sk);
+                Ok(Keypair::Secp256k1 { sk, pk })
+            }
+            KeyAlgorithm::Ed25519 => {
+                let sk = ed25519::SecretKey::from_slice(data)?;
+                let pk = ed25519::PublicKey::from(&sk);
+                Ok(Keypair::Ed25519 { sk, pk })
+            }
+        }
+    }
 
-        bytes[..SECRET_KEY_SIZE].copy_from_slice(self.sk.to_bytes().as_slice());
-        bytes[SECRET_KEY_SIZE..].copy_from_slice(self.pk.to_bytes().as_slice());
+    /// Import a secret key exported by iwallet, see `to_iost_string`
+    pub fn from_iost_string(algorithm: KeyAlgorithm, secret: &str) -> crate::Result<Keypair> {
+        match algorithm {
+            KeyAlgorithm::Secp256k1 => {
+                let sk = SecretKey::from_iost_string(secret)?;
+                let pk = PublicKey::from(&sk);
+                Ok(Keypair::Secp256k1 { sk, pk })
+            }
+            KeyAlgorithm::Ed25519 => {
+                let sk = ed25519::SecretKey::from_iost_string(secret)?;
+                let pk = ed25519::PublicKey::from(&sk);
+                Ok(Keypair::Ed25519 { sk, pk })
+            }
+        }
+    }
+
+    /// Export the secret key in the base58 format used by iwallet
+    pub fn to_iost_string(&self) -> String {
+        match self {
+            Keypair::Secp256k1 { sk, .. } => sk.to_iost_string(),
+            Keypair::Ed25519 { sk, .. } => sk.to_iost_string(),
+        }
+    }
+
+    /// The IOST public key ID of this keypair, as used in account permissions
+    pub fn public_key_iost_string(&self) -> String {
+        match self {
+            Keypair::Secp256k1 { pk, .. } => pk.to_iost_string(),
+            Keypair::Ed25519 { pk, .. } => pk.to_iost_string(),
+        }
+    }
+
+    /// The algorithm of this keypair.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match self {
+            Keypair::Secp256k1 { .. } => KeyAlgorithm::Secp256k1,
+            Keypair::Ed25519 { .. } => KeyAlgorithm::Ed25519,
+        }
+    }
+
+    /// The raw bytes of the secret half of this keypair.
+    pub fn secret_key_bytes(&self) -> Vec<u8> {
+        match self {
+            Keypair::Secp256k1 { sk, .. } => sk.to_bytes(),
+            Keypair::Ed25519 { sk, .. } => sk.to_bytes(),
+        }
+    }
+
+    /// The raw bytes of the public half of this keypair, as IOST expects them in a signature:
+    /// 33 bytes compressed for secp256k1, 32 bytes for ed25519.
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        match self {
+            Keypair::Secp256k1 { pk, .. } => pk.to_bytes(),
+            Keypair::Ed25519 { pk, .. } => pk.to_bytes(),
+        }
+    }
+
+    /// Convert this keypair to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.secret_key_bytes();
+        bytes.extend_from_slice(&self.public_key_bytes());
 
         bytes
     }
 
-    /// Sign a message with this keypair's secret key.
-    pub fn sign(&self, message: &[u8]) -> crate::Result<Signature> {
-        self.sk.sign(&message)
+    /// Sign a message with this keypair's secret key. secp256k1 hashes the message with sha256 first.
+    pub fn sign(&self, message: &[u8]) -> crate::Result<[u8; COMPACT_SIGNATURE_SIZE]> {
+        match self {
+            Keypair::Secp256k1 { sk, .. } => Ok(sk.sign(message)?.sig.serialize()),
+            Keypair::Ed25519 { sk, .. } => Ok(sk.sign(message)?.to_bytes()),
+        }
+    }
+
+    /// Sign a 32 byte hash with this keypair's secret key, the way IOST signs transactions.
+    pub fn sign_hash(&self, hash: &[u8]) -> crate::Result<[u8; COMPACT_SIGNATURE_SIZE]> {
+        match self {
+            Keypair::Secp256k1 { sk, .. } => Ok(sk.sign_hash(hash)?.sig.serialize()),
+            Keypair::Ed25519 { sk, .. } => Ok(sk.sign(hash)?.to_bytes()),
+        }
     }
 
     /// Verify a signature on a message with this keypair's public key
-    pub fn verify(&self, message: &[u8], signature: &Signature) -> crate::Result<()> {
-        self.pk.verify(&message, &signature)
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> crate::Result<()> {
+        match self {
+            Keypair::Secp256k1 { .. } => {
+                let hash = <bitcoin_hashes::sha256::Hash as bitcoin_hashes::Hash>::hash(message);
+                self.verify_hash(&hash, signature)
+            }
+            Keypair::Ed25519 { .. } => self.verify_hash(message, signature),
+        }
+    }
+
+    /// Verify a signature on a hash with this keypair's public key
+    pub fn verify_hash(&self, hash: &[u8], signature: &[u8]) -> crate::Result<()> {
+        match self {
+            Keypair::Secp256k1 { pk, .. } => {
+                let msg = secp256k1::Message::parse_slice(hash)?;
+                let sig = secp256k1::Signature::parse_slice(signature)?;
+                match secp256k1::verify(&msg, &sig, &pk.key) {
+                    true => Ok(()),
+                    false => Err(crate::error::Error::VerifyFailed),
+                }
+            }
+            Keypair::Ed25519 { pk, .. } => pk.verify(hash, &ed25519::Signature::from_slice(signature)?),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Keypair;
+    use crate::algorithm::KeyAlgorithm;
     use alloc::string::ToString;
     #[cfg(feature = "std")]
     use rand::thread_rng;
@@ -64,16 +190,52 @@ mod tests {
     #[test]
     fn keypair_generate_should_work() {
         let mut rng = thread_rng();
-        let keypair = Keypair::generate(&mut rng);
+        let keypair = Keypair::generate(KeyAlgorithm::Secp256k1, &mut rng);
+        match keypair {
+            Keypair::Secp256k1 { sk, pk } => assert_eq!(PublicKey::from(&sk), pk),
+            _ => panic!("expected a secp256k1 keypair"),
+        }
 
-        assert_eq!(PublicKey::from(&keypair.sk), keypair.pk);
+        let keypair = Keypair::generate(KeyAlgorithm::Ed25519, &mut rng);
+        assert_eq!(keypair.algorithm(), KeyAlgorithm::Ed25519);
+        assert_eq!(keypair.public_key_bytes().len(), 32);
+        assert_eq!(keypair.to_bytes().len(), 64);
     }
 
     #[test]
     fn keypair_from_secret_wif_should_work() {
         let wif = "5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4";
         let keypair = Keypair::from_secret_wif(wif).unwrap();
-        assert_eq!(keypair.pk.to_string(), "EOS8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMxKhAm");
+        match keypair {
+            Keypair::Secp256k1 { pk, .. } => assert_eq!(pk.to_string(), "EOS8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMxKhAm"),
+            _ => panic!("expected a secp256k1 keypair"),
+        }
+    }
+
+    #[test]
+    fn keypair_from_secret_bytes_should_work() {
+        let wif = "5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4";
+        let keypair = Keypair::from_secret_wif(wif).unwrap();
+        let restored = Keypair::from_secret_bytes(KeyAlgorithm::Secp256k1, &keypair.secret_key_bytes()).unwrap();
+        assert_eq!(restored.public_key_bytes(), keypair.public_key_bytes());
+
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        assert_eq!(keypair.algorithm(), KeyAlgorithm::Ed25519);
+        assert_eq!(keypair.secret_key_bytes(), [7u8; 32].to_vec());
+        assert!(Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 31]).is_err());
+    }
+
+    #[test]
+    fn keypair_from_iost_string_should_work() {
+        let secret = "2yquS3ySrGWPEKywCPzX4RTJugqRh7kJSo5aehsLYPEWkUxBWA39oMrZ7ZxuM4fgyXYs2cPwh5n8aNNpH5x2VyK1";
+        let keypair = Keypair::from_iost_string(KeyAlgorithm::Ed25519, secret).unwrap();
+        assert_eq!(keypair.public_key_iost_string(), "Gcv8c2tH8qZrUYnKdEEdTtASsxivic2834MQW6mgxqto");
+        assert_eq!(keypair.to_iost_string(), secret);
+        assert!(Keypair::from_iost_string(KeyAlgorithm::Secp256k1, secret).is_err());
+
+        let keypair = Keypair::from_secret_wif("5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4").unwrap();
+        let restored = Keypair::from_iost_string(KeyAlgorithm::Secp256k1, &keypair.to_iost_string()).unwrap();
+        assert_eq!(restored.public_key_iost_string(), keypair.public_key_iost_string());
     }
 
     #[test]
@@ -96,4 +258,14 @@ mod tests {
         let verify = keypair.verify(&message, &sig.unwrap());
         assert!(verify.is_ok());
     }
+
+    #[test]
+    fn keypair_ed25519_sign_hash_should_work() {
+        let keypair = Keypair::from_secret_bytes(KeyAlgorithm::Ed25519, &[7u8; 32]).unwrap();
+        let hash = [1u8; 32];
+        let sig = keypair.sign_hash(&hash).unwrap();
+        assert!(keypair.verify_hash(&hash, &sig).is_ok());
+        assert!(keypair.verify(&hash, &sig).is_ok());
+        assert!(keypair.verify_hash(&[2u8; 32], &sig).is_err());
+    }
 }
@@ -2,15 +2,17 @@
 
 extern crate alloc;
 
+pub mod algorithm;
+pub mod ed25519;
 pub mod keypair;
 pub mod public;
 pub mod secret;
 pub mod signature;
 pub mod error;
+pub mod base58;
 
 mod constant;
 mod hash;
-mod base58;
 mod network;
 
 use error::Result;
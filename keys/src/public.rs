@@ -39,6 +39,21 @@ impl PublicKey {
         format!("EOS{}", base58::encode_slice(&public_key))
     }
 
+    /// Serialize the public key to an IOST public key ID: base58 of the compressed key
+    pub fn to_iost_string(&self) -> String {
+        base58::encode_slice(&self.key.serialize_compressed())
+    }
+
+    /// Parse an IOST public key ID
+    pub fn from_iost_string(s: &str) -> crate::Result<PublicKey> {
+        let data = base58::from(s)?;
+        if data.len() != PUBLIC_KEY_SIZE {
+            return Err(base58::Error::InvalidLength(data.len()).into());
+        }
+
+        PublicKey::from_slice(&data)
+    }
+
     /// Verify a signature on a message with public key.
     pub fn verify(&self, message_slice: &[u8], signature: &Signature) -> crate::Result<()> {
         let msg_hash = sha256::Hash::hash(&message_slice);
@@ -141,6 +156,15 @@ mod test {
         assert_eq!(pk.unwrap_err(), error::Error::Secp256k1(secp256k1::Error::InvalidPublicKey));
     }
 
+    #[test]
+    fn pk_iost_string_should_work() {
+        let pk = PublicKey::from_str("EOS8FdQ4gt16pFcSiXAYCcHnkHTS2nNLFWGZXW5sioAdvQuMxKhAm").unwrap();
+        let s = pk.to_iost_string();
+        assert!(!s.starts_with("EOS"));
+        assert_eq!(PublicKey::from_iost_string(&s).unwrap(), pk);
+        assert!(PublicKey::from_iost_string("Gcv8c2tH8qZrUYnKdEEdTtASsxivic2834MQW6mgxqto").is_err());
+    }
+
     #[test]
     fn pk_verify_should_work() {
         let pk_str = "EOS86jwjSu9YkD4JDJ7nGK1Rx2SmvNMQ3XiKrvFndABzLDPwk1ZHx";
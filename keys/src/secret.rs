@@ -4,6 +4,7 @@ use alloc::string::String;
 use bitcoin_hashes::{sha256, Hash as HashTrait};
 use core::fmt::{self, Write};
 use core::str::FromStr;
+use crate::constant::SECRET_KEY_SIZE;
 use crate::error;
 use crate::network::Network;
 use crate::base58;
@@ -87,6 +88,25 @@ impl SecretKey {
         })
     }
 
+    /// Serialize the private key the way iwallet exports it: base58 of the raw 32 bytes
+    pub fn to_iost_string(&self) -> String {
+        base58::encode_slice(&self.key.serialize())
+    }
+
+    /// Parse a private key exported by iwallet
+    pub fn from_iost_string(s: &str) -> crate::Result<SecretKey> {
+        let data = base58::from(s)?;
+        if data.len() != SECRET_KEY_SIZE {
+            return Err(base58::Error::InvalidLength(data.len()).into());
+        }
+
+        Ok(SecretKey {
+            compressed: true,
+            network: Mainnet,
+            key: secp256k1::SecretKey::parse_slice(&data)?,
+        })
+    }
+
     /// Deserialize a secret key from a slice
     pub fn from_slice(data: &[u8]) -> crate::Result<SecretKey> {
         let compressed: bool = match data.len() {
@@ -161,6 +181,16 @@ mod test {
         assert!(sk.is_ok());
     }
 
+    #[test]
+    fn sk_iost_string_should_work() {
+        let sk = SecretKey::from_wif("5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4").unwrap();
+        let s = sk.to_iost_string();
+        let parsed = SecretKey::from_iost_string(&s).unwrap();
+        assert_eq!(parsed.key, sk.key);
+        assert_eq!(parsed.to_iost_string(), s);
+        assert!(SecretKey::from_iost_string("5HrBLKfeEdqH9KLMv1daHLVjrXV3DGVERAkN5cdSSc58bzqqfT4").is_err());
+    }
+
     #[test]
     fn sk_sign_should_work() {
         let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F");
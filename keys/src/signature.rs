@@ -22,6 +22,17 @@ impl Signature {
         data
     }
 
+    /// Parse a 64 byte signature without recovery id, as transmitted by IOST.
+    /// The recovery id is set to 0; it is not needed for verification.
+    pub fn from_slice(data: &[u8]) -> crate::Result<Self> {
+        let sig = secp256k1::Signature::parse_slice(data)?;
+        let recv_id = secp256k1::RecoveryId::parse(0)?;
+        Ok(Self {
+            recv_id,
+            sig,
+        })
+    }
+
     pub fn from_compact(data: &[u8; 65]) -> crate::Result<Self> {
         let recv_id = if data[0] >= 31 {
             data[0] - 4
@@ -1,9 +0,0 @@
-use serde::{Serialize,Deserialize};
-
-#[derive(Serialize, Deserialize, Debug)]
-pub enum Status {
-    PENDING,
-    PACKED,
-    IRREVERSIBLE,
-    APPROVED
-}
\ No newline at end of file